@@ -0,0 +1,296 @@
+//! A reference table of the ascii characters, like the one shown by `man ascii`.
+//!
+//! Each row gives a code in decimal, hexadecimal, octal and binary along with its character,
+//! or the abbreviation and name of a control code. The table covers `0 - 127`, and can be
+//! extended to `255` with the upper half of an eight bit [`CodePage`]. It can be rendered as
+//! aligned text, a Markdown table or CSV.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::ascii_table::*;
+//!
+//! let table = ascii_table(TableFormat::Csv, None);
+//!
+//! assert_eq!(table.lines().count(), 129);
+//! assert_eq!(table.lines().next().unwrap(), "Dec,Hex,Oct,Bin,Char,Name");
+//! assert_eq!(table.lines().nth(28).unwrap(), "27,1B,33,0011011,ESC,Escape");
+//! assert_eq!(table.lines().nth(105).unwrap(), "104,68,150,1101000,h,");
+//! ```
+
+use crate::{dec_to_bit, dec_to_hex, decimals_to_string};
+
+/// The abbreviations and names of the control codes `0 - 31`.
+pub(crate) const CONTROL_CODES: [(&str, &str); 32] = [
+    ("NUL", "Null"), ("SOH", "Start of Heading"), ("STX", "Start of Text"), ("ETX", "End of Text"),
+    ("EOT", "End of Transmission"), ("ENQ", "Enquiry"), ("ACK", "Acknowledge"), ("BEL", "Bell"),
+    ("BS", "Backspace"), ("HT", "Horizontal Tab"), ("LF", "Line Feed"), ("VT", "Vertical Tab"),
+    ("FF", "Form Feed"), ("CR", "Carriage Return"), ("SO", "Shift Out"), ("SI", "Shift In"),
+    ("DLE", "Data Link Escape"), ("DC1", "Device Control 1"), ("DC2", "Device Control 2"), ("DC3", "Device Control 3"),
+    ("DC4", "Device Control 4"), ("NAK", "Negative Acknowledge"), ("SYN", "Synchronous Idle"), ("ETB", "End of Transmission Block"),
+    ("CAN", "Cancel"), ("EM", "End of Medium"), ("SUB", "Substitute"), ("ESC", "Escape"),
+    ("FS", "File Separator"), ("GS", "Group Separator"), ("RS", "Record Separator"), ("US", "Unit Separator"),
+];
+
+/// The abbreviation and name of code `127`.
+pub(crate) const DELETE: (&str, &str) = ("DEL", "Delete");
+
+/// The abbreviations and names of the C1 control codes `128 - 159` used by ISO 8859-1.
+const C1_CONTROL_CODES: [(&str, &str); 32] = [
+    ("PAD", "Padding Character"), ("HOP", "High Octet Preset"), ("BPH", "Break Permitted Here"), ("NBH", "No Break Here"),
+    ("IND", "Index"), ("NEL", "Next Line"), ("SSA", "Start of Selected Area"), ("ESA", "End of Selected Area"),
+    ("HTS", "Character Tabulation Set"), ("HTJ", "Character Tabulation with Justification"), ("VTS", "Line Tabulation Set"), ("PLD", "Partial Line Forward"),
+    ("PLU", "Partial Line Backward"), ("RI", "Reverse Line Feed"), ("SS2", "Single Shift Two"), ("SS3", "Single Shift Three"),
+    ("DCS", "Device Control String"), ("PU1", "Private Use One"), ("PU2", "Private Use Two"), ("STS", "Set Transmit State"),
+    ("CCH", "Cancel Character"), ("MW", "Message Waiting"), ("SPA", "Start of Guarded Area"), ("EPA", "End of Guarded Area"),
+    ("SOS", "Start of String"), ("SGCI", "Single Graphic Character Introducer"), ("SCI", "Single Character Introducer"), ("CSI", "Control Sequence Introducer"),
+    ("ST", "String Terminator"), ("OSC", "Operating System Command"), ("PM", "Privacy Message"), ("APC", "Application Program Command"),
+];
+
+/// The characters of Windows-1252 at `128 - 159`, where it differs from ISO 8859-1.
+const WINDOWS_1252: [Option<char>; 32] = [
+    Some('€'), None, Some('‚'), Some('ƒ'), Some('„'), Some('…'), Some('†'), Some('‡'),
+    Some('ˆ'), Some('‰'), Some('Š'), Some('‹'), Some('Œ'), None, Some('Ž'), None,
+    None, Some('‘'), Some('’'), Some('“'), Some('”'), Some('•'), Some('–'), Some('—'),
+    Some('˜'), Some('™'), Some('š'), Some('›'), Some('œ'), None, Some('ž'), Some('Ÿ'),
+];
+
+const HEADINGS: [&str; 6] = ["Dec", "Hex", "Oct", "Bin", "Char", "Name"];
+
+/// The eight bit code pages that can fill the upper half of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodePage {
+    /// ISO 8859-1, with the C1 control codes at `128 - 159`.
+    Latin1,
+    /// Windows-1252, which places printable characters over most of the C1 control codes.
+    Windows1252,
+}
+
+/// The formats the table can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    /// Columns padded with spaces so they line up, with a line of dashes under the headings.
+    Text,
+    /// A GitHub flavoured Markdown table.
+    Markdown,
+    /// Comma separated values, quoting fields as RFC 4180 does.
+    Csv,
+}
+
+/// One row of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRow {
+    /// The code.
+    pub decimal: u8,
+    /// The code as two hexadecimal digits.
+    pub hexadecimal: String,
+    /// The code in octal.
+    pub octal: String,
+    /// The code in binary, seven digits for ascii and eight for the upper half.
+    pub binary: String,
+    /// The printable character, if the code has one.
+    pub character: Option<char>,
+    /// The abbreviation of a control code, such as `ESC`.
+    pub abbreviation: Option<&'static str>,
+    /// The name of a control code or of a character that can't be seen, such as `Escape` or `Space`.
+    pub name: Option<&'static str>,
+}
+
+/// This function returns the rows of the table.
+///
+/// Without a code page the rows cover `0 - 127`, with one they cover `0 - 255`. Codes the
+/// code page leaves undefined have no character, abbreviation or name.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ascii_table::*;
+///
+/// let rows = table_rows(Some(CodePage::Windows1252));
+///
+/// assert_eq!(rows.len(), 256);
+/// assert_eq!(rows[0x80].character, Some('€'));
+/// assert_eq!(rows[0x7F].abbreviation, Some("DEL"));
+/// assert_eq!(rows[0xFF].binary, "11111111");
+/// ```
+pub fn table_rows(code_page: Option<CodePage>) -> Vec<TableRow> {
+    let last: u8 = if code_page.is_some() { 255 } else { 127 };
+    let bits = if code_page.is_some() { 8 } else { 7 };
+
+    (0..=last)
+        .map(|d| {
+            let (character, control) = match (d, code_page) {
+                (0..=31, _) => (None, Some(CONTROL_CODES[d as usize])),
+                (32..=126, _) => (decimals_to_string(&vec![d]).ok().and_then(|s| s.chars().next()), None),
+                (127, _) => (None, Some(DELETE)),
+                (128..=159, Some(CodePage::Windows1252)) => (WINDOWS_1252[d as usize - 128], None),
+                (128..=159, _) => (None, Some(C1_CONTROL_CODES[d as usize - 128])),
+                _ => (Some(d as char), None),
+            };
+
+            let (abbreviation, name) = match (d, control) {
+                (_, Some((abbreviation, name))) => (Some(abbreviation), Some(name)),
+                (32, _) => (None, Some("Space")),
+                (0xA0, _) => (None, Some("No-Break Space")),
+                (0xAD, _) => (None, Some("Soft Hyphen")),
+                _ => (None, None),
+            };
+
+            TableRow {
+                decimal: d,
+                hexadecimal: format!("{:0>2}", dec_to_hex(d)),
+                octal: format!("{:o}", d),
+                binary: format!("{:0width$}", dec_to_bit(d), width = bits),
+                character,
+                abbreviation,
+                name,
+            }
+        })
+        .collect()
+}
+
+/// This function renders the table in the chosen format.
+///
+/// The `Char` column holds the character, or the abbreviation for control codes, and the
+/// `Name` column holds the name of control codes and characters that can't be seen.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ascii_table::*;
+///
+/// let table = ascii_table(TableFormat::Markdown, None);
+/// let lines: Vec<&str> = table.lines().collect();
+///
+/// assert_eq!(lines[0], "| Dec | Hex | Oct | Bin     | Char | Name                      |");
+/// assert_eq!(lines[1], "|-----|-----|-----|---------|------|---------------------------|");
+/// assert_eq!(lines[2], "| 0   | 00  | 0   | 0000000 | NUL  | Null                      |");
+/// assert_eq!(lines[126], "| 124 | 7C  | 174 | 1111100 | \\|   |                           |");
+/// ```
+pub fn ascii_table(format: TableFormat, code_page: Option<CodePage>) -> String {
+    let mut cells: Vec<Vec<String>> = vec![HEADINGS.iter().map(|h| h.to_string()).collect()];
+
+    for row in table_rows(code_page) {
+        let character = match (row.character, row.abbreviation) {
+            (Some(c), _) => c.to_string(),
+            (None, Some(abbreviation)) => abbreviation.to_string(),
+            (None, None) => String::new(),
+        };
+
+        let mut line = vec![row.decimal.to_string(), row.hexadecimal, row.octal, row.binary, character, row.name.unwrap_or("").to_string()];
+
+        match format {
+            TableFormat::Markdown => line[4] = line[4].replace('|', "\\|"),
+            TableFormat::Csv => line[4] = csv_field(&line[4]),
+            TableFormat::Text => {}
+        }
+
+        cells.push(line);
+    }
+
+    if format == TableFormat::Csv {
+        return cells.iter().map(|line| format!("{}\n", line.join(","))).collect();
+    }
+
+    let widths: Vec<usize> = (0..HEADINGS.len())
+        .map(|i| cells.iter().map(|line| line[i].chars().count()).max().unwrap_or(0))
+        .collect();
+
+    let pad = |line: &[String]| -> Vec<String> {
+        line.iter().zip(widths.iter()).map(|(cell, width)| format!("{:<width$}", cell, width = width)).collect()
+    };
+
+    let dashes: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    let mut table = String::new();
+
+    for (i, line) in cells.iter().enumerate() {
+        match format {
+            TableFormat::Text => table.push_str(pad(line).join("  ").trim_end()),
+            _ => table.push_str(&format!("| {} |", pad(line).join(" | "))),
+        }
+
+        table.push('\n');
+
+        if i == 0 {
+            match format {
+                TableFormat::Text => table.push_str(&dashes.join("  ")),
+                _ => table.push_str(&format!("|-{}-|", dashes.join("-|-"))),
+            }
+
+            table.push('\n');
+        }
+    }
+
+    table
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod table_rows_tests {
+        use super::super::*;
+
+        #[test]
+        fn table_rows_test_ascii() {
+            let rows = table_rows(None);
+
+            assert_eq!(rows.len(), 128);
+            assert!(rows.iter().enumerate().all(|(i, row)| row.decimal as usize == i));
+            assert_eq!(rows.iter().filter(|row| row.abbreviation.is_some()).count(), 33);
+            assert_eq!(rows.iter().filter(|row| row.character.is_some()).count(), 95);
+            assert_eq!(rows[32].name, Some("Space"));
+            assert_eq!(rows[10].octal, "12");
+        }
+
+        #[test]
+        fn table_rows_test_code_pages() {
+            let latin1 = table_rows(Some(CodePage::Latin1));
+            let windows = table_rows(Some(CodePage::Windows1252));
+
+            assert_eq!(latin1[0x85].abbreviation, Some("NEL"));
+            assert_eq!(windows[0x85].character, Some('…'));
+            assert_eq!(windows[0x81], TableRow {
+                decimal: 0x81,
+                hexadecimal: "81".to_string(),
+                octal: "201".to_string(),
+                binary: "10000001".to_string(),
+                character: None,
+                abbreviation: None,
+                name: None,
+            });
+            assert_eq!(latin1[0xE9].character, Some('é'));
+            assert_eq!(latin1[0xA0..], windows[0xA0..]);
+        }
+    }
+
+    mod ascii_table_tests {
+        use super::super::*;
+
+        #[test]
+        fn ascii_table_test_text() {
+            let table = ascii_table(TableFormat::Text, None);
+            let lines: Vec<&str> = table.lines().collect();
+
+            assert_eq!(lines.len(), 130);
+            assert_eq!(lines[0], "Dec  Hex  Oct  Bin      Char  Name");
+            assert_eq!(lines[1], "---  ---  ---  -------  ----  -------------------------");
+            assert_eq!(lines[34], "32   20   40   0100000        Space");
+            assert_eq!(lines[67], "65   41   101  1000001  A");
+        }
+
+        #[test]
+        fn ascii_table_test_csv_quoting() {
+            let table = ascii_table(TableFormat::Csv, Some(CodePage::Windows1252));
+
+            assert_eq!(table.lines().count(), 257);
+            assert_eq!(table.lines().nth(35).unwrap(), "34,22,42,00100010,\"\"\"\",");
+            assert_eq!(table.lines().nth(45).unwrap(), "44,2C,54,00101100,\",\",");
+            assert_eq!(table.lines().nth(129).unwrap(), "128,80,200,10000000,€,");
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Conversion between ascii text and five bit Baudot (ITA2) codes.
+//!
+//! Baudot codes only have 32 values, so each code has a letter meaning and a figure meaning.
+//! The `LTRS` and `FIGS` codes switch between the two, the encoder inserts them whenever the
+//! next character needs the other shift and the decoder keeps track of the current shift.
+//!
+//! Both the international ITA2 figures and the US teletype (US-TTY) figures are supported,
+//! chosen with [`BaudotVariant`].
+//!
+//! # Example
+//! ```
+//! use ascii_converter::baudot::*;
+//!
+//! let codes = string_to_baudot("RY 73", BaudotVariant::Ita2).unwrap();
+//!
+//! assert_eq!(codes, vec![31, 10, 21, 4, 27, 7, 1]);
+//! assert_eq!(baudot_to_string(&codes, BaudotVariant::Ita2).unwrap(), "RY 73");
+//! ```
+
+use crate::{binary_to_decimal, decimals_to_binary};
+
+/// The code that switches to the figures shift.
+pub const FIGS: u8 = 0x1B;
+
+/// The code that switches to the letters shift.
+pub const LTRS: u8 = 0x1F;
+
+const LETTERS: [Option<char>; 32] = [
+    Some('\0'), Some('E'), Some('\n'), Some('A'), Some(' '), Some('S'), Some('I'), Some('U'),
+    Some('\r'), Some('D'), Some('R'), Some('J'), Some('N'), Some('F'), Some('C'), Some('K'),
+    Some('T'), Some('Z'), Some('L'), Some('W'), Some('H'), Some('Y'), Some('P'), Some('Q'),
+    Some('O'), Some('B'), Some('G'), None, Some('M'), Some('X'), Some('V'), None,
+];
+
+// The positions for F, H and G are left for national use in ITA2.
+const ITA2_FIGURES: [Option<char>; 32] = [
+    Some('\0'), Some('3'), Some('\n'), Some('-'), Some(' '), Some('\''), Some('8'), Some('7'),
+    Some('\r'), Some('\u{5}'), Some('4'), Some('\u{7}'), Some(','), None, Some(':'), Some('('),
+    Some('5'), Some('+'), Some(')'), Some('2'), None, Some('6'), Some('0'), Some('1'),
+    Some('9'), Some('?'), None, None, Some('.'), Some('/'), Some('='), None,
+];
+
+const US_TTY_FIGURES: [Option<char>; 32] = [
+    Some('\0'), Some('3'), Some('\n'), Some('-'), Some(' '), Some('\u{7}'), Some('8'), Some('7'),
+    Some('\r'), Some('$'), Some('4'), Some('\''), Some(','), Some('!'), Some(':'), Some('('),
+    Some('5'), Some('"'), Some(')'), Some('2'), Some('#'), Some('6'), Some('0'), Some('1'),
+    Some('9'), Some('?'), Some('&'), None, Some('.'), Some('/'), Some(';'), None,
+];
+
+/// The Baudot variants that can be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaudotVariant {
+    /// International Telegraph Alphabet No. 2.
+    Ita2,
+    /// The US teletype variant of ITA2, with different figures for some codes.
+    UsTty,
+}
+
+impl BaudotVariant {
+    fn figures(self) -> &'static [Option<char>; 32] {
+        match self {
+            BaudotVariant::Ita2 => &ITA2_FIGURES,
+            BaudotVariant::UsTty => &US_TTY_FIGURES,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Shift {
+    Letters,
+    Figures,
+}
+
+/// This function converts text into Baudot codes.
+///
+/// Letters are case insensitive. A `LTRS` or `FIGS` code is inserted before the first character
+/// and whenever the next character is in the other shift. Space, carriage return, line feed and
+/// null are in both shifts so they never cause a shift code.
+///
+/// If a character has no Baudot equivalent an error is returned naming the character and its index.
+///
+/// # Example
+/// ```
+/// use ascii_converter::baudot::*;
+///
+/// assert_eq!(string_to_baudot("A1", BaudotVariant::Ita2).unwrap(), vec![LTRS, 3, FIGS, 23]);
+/// ```
+pub fn string_to_baudot(txt: &str, variant: BaudotVariant) -> Result<Vec<u8>, String> {
+    let mut codes = Vec::new();
+    let mut shift = None;
+
+    for (i, c) in txt.chars().enumerate() {
+        let c = c.to_ascii_uppercase();
+
+        let letter = LETTERS.iter().position(|l| *l == Some(c));
+        let figure = variant.figures().iter().position(|f| *f == Some(c));
+
+        match (letter, figure) {
+            (Some(l), Some(_)) => codes.push(l as u8),
+            (Some(l), None) => {
+                if shift != Some(Shift::Letters) {
+                    codes.push(LTRS);
+                    shift = Some(Shift::Letters);
+                }
+
+                codes.push(l as u8);
+            }
+            (None, Some(f)) => {
+                if shift != Some(Shift::Figures) {
+                    codes.push(FIGS);
+                    shift = Some(Shift::Figures);
+                }
+
+                codes.push(f as u8);
+            }
+            (None, None) => return Err(format!("the character {:?} at index {} has no baudot equivalent", c, i)),
+        }
+    }
+
+    Ok(codes)
+}
+
+/// This function converts Baudot codes into text.
+///
+/// Decoding starts in the letters shift and the `LTRS` and `FIGS` codes change the shift
+/// without adding to the text.
+///
+/// An error will be thrown if a code is above `31` or has no meaning in the current shift.
+///
+/// # Example
+/// ```
+/// use ascii_converter::baudot::*;
+///
+/// assert_eq!(baudot_to_string(&[20, 6, FIGS, 27, 24], BaudotVariant::UsTty).unwrap(), "HI9");
+/// ```
+pub fn baudot_to_string(codes: &[u8], variant: BaudotVariant) -> Result<String, String> {
+    let mut text = String::new();
+    let mut shift = Shift::Letters;
+
+    for code in codes.iter() {
+        match *code {
+            LTRS => shift = Shift::Letters,
+            FIGS => shift = Shift::Figures,
+            c if c < 32 => {
+                let table = if shift == Shift::Letters { &LETTERS } else { variant.figures() };
+
+                match table[c as usize] {
+                    Some(ch) => text.push(ch),
+                    None => return Err(format!("the baudot code {} has no character in the figures shift", c)),
+                }
+            }
+            c => return Err(format!("{} isn't a five bit baudot code", c)),
+        }
+    }
+
+    Ok(text)
+}
+
+/// This function converts text into Baudot codes shown as binary numbers.
+///
+/// This works the same as [`string_to_baudot`] with each code returned in the crate's binary form.
+///
+/// # Example
+/// ```
+/// use ascii_converter::baudot::*;
+///
+/// assert_eq!(string_to_baudot_binary("E", BaudotVariant::Ita2).unwrap(), vec![11111, 1]);
+/// ```
+pub fn string_to_baudot_binary(txt: &str, variant: BaudotVariant) -> Result<Vec<u32>, String> {
+    decimals_to_binary(&string_to_baudot(txt, variant)?)
+}
+
+/// This function converts Baudot codes shown as binary numbers into text.
+///
+/// If a number passed in isn't binary an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::baudot::*;
+///
+/// assert_eq!(baudot_binary_to_string(&[11111, 1, 100, 11110], BaudotVariant::Ita2).unwrap(), "E V");
+/// ```
+pub fn baudot_binary_to_string(bin_vec: &[u32], variant: BaudotVariant) -> Result<String, String> {
+    baudot_to_string(&binary_to_decimal(&bin_vec.to_vec())?, variant)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod string_to_baudot_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_baudot_test_happy_path() {
+            let expected = vec![LTRS, 20, 1, 18, 18, 24, 4, FIGS, 19, 0x1C, 16, 4, LTRS, 14];
+
+            assert_eq!(string_to_baudot("hello 2.5 c", BaudotVariant::Ita2), Ok(expected));
+        }
+
+        #[test]
+        fn string_to_baudot_test_us_tty_figures() {
+            assert_eq!(string_to_baudot("$5!", BaudotVariant::UsTty), Ok(vec![FIGS, 9, 16, 13]));
+        }
+
+        #[test]
+        fn string_to_baudot_test_unhappy_path() {
+            assert_eq!(string_to_baudot("$5", BaudotVariant::Ita2), Err("the character '$' at index 0 has no baudot equivalent".to_string()));
+            assert_eq!(string_to_baudot("ab@", BaudotVariant::UsTty), Err("the character '@' at index 2 has no baudot equivalent".to_string()));
+        }
+    }
+
+    mod baudot_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn baudot_to_string_test_round_trip() {
+            let input = "THE QUICK BROWN FOX (1234567890) 'JUMPS'?\r\n";
+
+            for variant in [BaudotVariant::Ita2, BaudotVariant::UsTty].iter() {
+                let input = if *variant == BaudotVariant::Ita2 { input.to_string() } else { input.replace('\'', "\"") };
+                let codes = string_to_baudot(&input, *variant).unwrap();
+
+                assert_eq!(baudot_to_string(&codes, *variant), Ok(input));
+            }
+        }
+
+        #[test]
+        fn baudot_to_string_test_unhappy_path() {
+            assert_eq!(baudot_to_string(&[32], BaudotVariant::Ita2), Err("32 isn't a five bit baudot code".to_string()));
+            assert_eq!(baudot_to_string(&[FIGS, 13], BaudotVariant::Ita2), Err("the baudot code 13 has no character in the figures shift".to_string()));
+        }
+
+        #[test]
+        fn baudot_binary_to_string_test_unhappy_path() {
+            assert_eq!(baudot_binary_to_string(&[12], BaudotVariant::Ita2), Err("number passed in isn't binary".to_string()));
+        }
+    }
+}
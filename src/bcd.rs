@@ -0,0 +1,238 @@
+//! Binary-coded decimal (BCD) and packed BCD.
+//!
+//! Unpacked BCD stores one decimal digit per byte. Packed BCD stores two digits per byte, one in
+//! each nibble, and can end with a sign nibble chosen with [`SignNibble`].
+//!
+//! Packed BCD bytes can be shown with [`packed_bcd_to_hexadecimal`] and [`packed_bcd_to_binary`],
+//! and read back from hexadecimal or binary with the crate's `hexadecimal_to_decimal` and
+//! `binary_to_decimal` functions.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::bcd::*;
+//!
+//! let packed = string_to_packed_bcd("12345", SignNibble::Negative).unwrap();
+//!
+//! assert_eq!(packed_bcd_to_hexadecimal(&packed), vec!["12", "34", "5D"]);
+//! assert_eq!(packed_bcd_to_string(&packed).unwrap(), "-12345");
+//! ```
+
+use crate::{dec_to_bit, dec_to_hex};
+
+/// The sign nibble added to the end of packed BCD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignNibble {
+    /// No sign nibble, only digits are packed.
+    None,
+    /// `C`, a positive number.
+    Positive,
+    /// `D`, a negative number.
+    Negative,
+    /// `F`, an unsigned number.
+    Unsigned,
+}
+
+impl SignNibble {
+    fn nibble(self) -> Option<u8> {
+        match self {
+            SignNibble::None => None,
+            SignNibble::Positive => Some(0xC),
+            SignNibble::Negative => Some(0xD),
+            SignNibble::Unsigned => Some(0xF),
+        }
+    }
+}
+
+/// This function converts a string of ascii digits into unpacked BCD.
+///
+/// If the string contains a character that isn't a digit `0 - 9` an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::bcd::*;
+///
+/// assert_eq!(string_to_bcd("2024").unwrap(), vec![2, 0, 2, 4]);
+/// ```
+pub fn string_to_bcd(digits: &str) -> Result<Vec<u8>, String> {
+    let mut bcd = Vec::new();
+
+    for c in digits.chars() {
+        match c.to_digit(10) {
+            Some(d) => bcd.push(d as u8),
+            None => return Err(format!("the character {:?} isn't a decimal digit", c)),
+        }
+    }
+
+    Ok(bcd)
+}
+
+/// This function converts unpacked BCD into a string of ascii digits.
+///
+/// Each value passed in should be in the range `0 - 9`, otherwise an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::bcd::*;
+///
+/// assert_eq!(bcd_to_string(&[2, 0, 2, 4]).unwrap(), "2024");
+/// ```
+pub fn bcd_to_string(bcd: &[u8]) -> Result<String, String> {
+    let mut digits = String::new();
+
+    for (i, b) in bcd.iter().enumerate() {
+        if *b > 9 {
+            return Err(format!("invalid BCD digit {} at index {}", b, i));
+        }
+
+        digits.push((b'0' + b) as char);
+    }
+
+    Ok(digits)
+}
+
+/// This function converts a string of ascii digits into packed BCD.
+///
+/// The sign nibble, if any, is placed after the last digit. When there is an odd number of
+/// nibbles a `0` nibble is added to the front so the bytes are full.
+///
+/// If the string contains a character that isn't a digit `0 - 9` an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::bcd::*;
+///
+/// assert_eq!(string_to_packed_bcd("1234", SignNibble::None).unwrap(), vec![0x12, 0x34]);
+/// assert_eq!(string_to_packed_bcd("1234", SignNibble::Positive).unwrap(), vec![0x01, 0x23, 0x4C]);
+/// ```
+pub fn string_to_packed_bcd(digits: &str, sign: SignNibble) -> Result<Vec<u8>, String> {
+    let mut nibbles = string_to_bcd(digits)?;
+
+    if let Some(s) = sign.nibble() {
+        nibbles.push(s);
+    }
+
+    if nibbles.len() % 2 == 1 {
+        nibbles.insert(0, 0);
+    }
+
+    Ok(nibbles.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect())
+}
+
+/// This function converts packed BCD into a string of ascii digits.
+///
+/// If the last nibble is a sign nibble it is removed, and the string starts with `-` when it is
+/// a negative sign (`B` or `D`). Any padding `0` nibble at the front is kept.
+///
+/// If any other nibble isn't a digit `0 - 9` an error is thrown naming the byte it was found in.
+///
+/// # Example
+/// ```
+/// use ascii_converter::bcd::*;
+///
+/// assert_eq!(packed_bcd_to_string(&[0x12, 0x34]).unwrap(), "1234");
+/// assert_eq!(packed_bcd_to_string(&[0x99, 0x9F]).unwrap(), "999");
+/// assert_eq!(packed_bcd_to_string(&[0x04, 0x2D]).unwrap(), "-042");
+/// ```
+pub fn packed_bcd_to_string(packed: &[u8]) -> Result<String, String> {
+    let mut digits = String::new();
+    let mut negative = false;
+
+    for (i, byte) in packed.iter().enumerate() {
+        let last = i == packed.len() - 1;
+
+        for (n, nibble) in [byte >> 4, byte & 0xF].iter().enumerate() {
+            if *nibble <= 9 {
+                digits.push((b'0' + nibble) as char);
+            } else if last && n == 1 {
+                negative = *nibble == 0xB || *nibble == 0xD;
+            } else {
+                return Err(format!("invalid BCD nibble {} in byte {}", dec_to_hex(*nibble), i));
+            }
+        }
+    }
+
+    if negative {
+        digits.insert(0, '-');
+    }
+
+    Ok(digits)
+}
+
+/// This function returns packed BCD in the crate's hexadecimal form.
+///
+/// Unlike the ascii functions every byte is written with two digits, so each hexadecimal
+/// string reads as the two decimal digits it holds.
+///
+/// # Example
+/// ```
+/// use ascii_converter::bcd::*;
+///
+/// assert_eq!(packed_bcd_to_hexadecimal(&[0x01, 0x99]), vec!["01", "99"]);
+/// ```
+pub fn packed_bcd_to_hexadecimal(packed: &[u8]) -> Vec<String> {
+    packed.iter().map(|b| format!("{:0>2}", dec_to_hex(*b))).collect()
+}
+
+/// This function returns packed BCD in the crate's binary form.
+///
+/// # Example
+/// ```
+/// use ascii_converter::bcd::*;
+///
+/// assert_eq!(packed_bcd_to_binary(&[0x01, 0x99]), vec![1, 10011001]);
+/// ```
+pub fn packed_bcd_to_binary(packed: &[u8]) -> Vec<u32> {
+    packed.iter().map(|b| dec_to_bit(*b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod string_to_bcd_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_bcd_test_unhappy_path() {
+            assert_eq!(string_to_bcd("12a"), Err("the character 'a' isn't a decimal digit".to_string()));
+        }
+
+        #[test]
+        fn bcd_to_string_test_unhappy_path() {
+            assert_eq!(bcd_to_string(&[1, 10]), Err("invalid BCD digit 10 at index 1".to_string()));
+        }
+    }
+
+    mod string_to_packed_bcd_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_packed_bcd_test_sign_nibbles() {
+            assert_eq!(string_to_packed_bcd("123", SignNibble::None), Ok(vec![0x01, 0x23]));
+            assert_eq!(string_to_packed_bcd("123", SignNibble::Positive), Ok(vec![0x12, 0x3C]));
+            assert_eq!(string_to_packed_bcd("123", SignNibble::Negative), Ok(vec![0x12, 0x3D]));
+            assert_eq!(string_to_packed_bcd("123", SignNibble::Unsigned), Ok(vec![0x12, 0x3F]));
+        }
+
+        #[test]
+        fn string_to_packed_bcd_test_empty() {
+            assert_eq!(string_to_packed_bcd("", SignNibble::None), Ok(vec![]));
+        }
+    }
+
+    mod packed_bcd_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn packed_bcd_to_string_test_round_trip() {
+            let packed = string_to_packed_bcd("9876543210", SignNibble::Positive).unwrap();
+
+            assert_eq!(packed_bcd_to_string(&packed), Ok("09876543210".to_string()));
+        }
+
+        #[test]
+        fn packed_bcd_to_string_test_unhappy_path() {
+            assert_eq!(packed_bcd_to_string(&[0x1A, 0x23]), Err("invalid BCD nibble A in byte 0".to_string()));
+            assert_eq!(packed_bcd_to_string(&[0xC1]), Err("invalid BCD nibble C in byte 0".to_string()));
+        }
+    }
+}
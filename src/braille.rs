@@ -0,0 +1,215 @@
+//! Conversion between ascii text, North American Braille ASCII and Unicode Braille patterns.
+//!
+//! Braille ASCII maps the 64 ascii characters `32 - 95` onto the 64 possible six-dot cells.
+//! Lower case letters and the other characters `96 - 126` share the cells of the characters
+//! 32 below them, so they are folded to upper case before converting.
+//!
+//! Cells can be written in three ways, chosen with [`BrailleFormat`]:
+//!
+//! - `Ascii` - the Braille ASCII character, e.g. `H`.
+//!
+//! - `Unicode` - a character from the Unicode Braille Patterns block (`U+2800`), e.g. `⠓`.
+//!
+//! - `Dots` - the raised dot numbers separated by spaces, e.g. `125`. The blank cell is written `0`.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::braille::*;
+//!
+//! assert_eq!(string_to_braille("Hello", BrailleFormat::Unicode).unwrap(), "⠓⠑⠇⠇⠕");
+//! assert_eq!(braille_to_string("⠓⠑⠇⠇⠕", BrailleFormat::Unicode).unwrap(), "HELLO");
+//! ```
+
+// Braille ASCII characters ordered by their Unicode pattern, so the index is the dot bit mask.
+const CELLS: &str = " A1B'K2L@CIF/MSP\"E3H9O6R^DJG>NTQ,*5<-U8V.%[$+X!&;:4\\0Z7(_?W]#Y)=";
+
+const UNICODE_BASE: u32 = 0x2800;
+
+/// The ways a braille cell can be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrailleFormat {
+    /// North American Braille ASCII characters.
+    Ascii,
+    /// Characters from the Unicode Braille Patterns block.
+    Unicode,
+    /// Dot numbers such as `125`, one group per cell separated by spaces.
+    Dots,
+}
+
+/// This function converts ascii text into braille cells.
+///
+/// Each character of the `&str` passed in is folded into the Braille ASCII range `32 - 95`
+/// and then written in the format requested.
+///
+/// Characters outside the printable ascii range `32 - 126` will cause an error.
+///
+/// # Example
+/// ```
+/// use ascii_converter::braille::*;
+///
+/// assert_eq!(string_to_braille("Hi!", BrailleFormat::Ascii).unwrap(), "HI!");
+/// assert_eq!(string_to_braille("Hi!", BrailleFormat::Dots).unwrap(), "125 24 2346");
+/// ```
+pub fn string_to_braille(txt: &str, format: BrailleFormat) -> Result<String, String> {
+    let mut masks = Vec::new();
+
+    for c in txt.chars() {
+        match char_to_mask(c) {
+            Some(mask) => masks.push(mask),
+            None => return Err(format!("the character {:?} has no braille ascii equivalent", c)),
+        }
+    }
+
+    Ok(match format {
+        BrailleFormat::Ascii => masks.iter().map(|m| mask_to_char(*m)).collect(),
+        BrailleFormat::Unicode => masks.iter().map(|m| mask_to_unicode(*m)).collect(),
+        BrailleFormat::Dots => masks.iter().map(|m| mask_to_dots(*m)).collect::<Vec<String>>().join(" "),
+    })
+}
+
+/// This function converts braille cells back into Braille ASCII text.
+///
+/// The input should be written in the format passed in. Letters are returned in upper case
+/// as Braille ASCII has no separate lower case cells.
+///
+/// An error is returned if a cell isn't valid for the format, such as a character outside
+/// the six-dot Unicode patterns `U+2800 - U+283F` or a dot number above `6`.
+///
+/// # Example
+/// ```
+/// use ascii_converter::braille::*;
+///
+/// assert_eq!(braille_to_string("125 24 2346", BrailleFormat::Dots).unwrap(), "HI!");
+/// assert_eq!(braille_to_string("hi!", BrailleFormat::Ascii).unwrap(), "HI!");
+/// ```
+pub fn braille_to_string(braille: &str, format: BrailleFormat) -> Result<String, String> {
+    let mut text = String::new();
+
+    match format {
+        BrailleFormat::Ascii => {
+            for c in braille.chars() {
+                match char_to_mask(c) {
+                    Some(mask) => text.push(mask_to_char(mask)),
+                    None => return Err(format!("the character {:?} isn't braille ascii", c)),
+                }
+            }
+        }
+        BrailleFormat::Unicode => {
+            for c in braille.chars() {
+                let code = c as u32;
+
+                if !(UNICODE_BASE..UNICODE_BASE + 64).contains(&code) {
+                    return Err(format!("the character {:?} isn't a six-dot braille pattern", c));
+                }
+
+                text.push(mask_to_char((code - UNICODE_BASE) as u8));
+            }
+        }
+        BrailleFormat::Dots => {
+            for cell in braille.split_whitespace() {
+                text.push(mask_to_char(dots_to_mask(cell)?));
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+fn char_to_mask(c: char) -> Option<u8> {
+    if !(' '..='~').contains(&c) {
+        return None;
+    }
+
+    let folded = if c >= '`' { (c as u8 - 32) as char } else { c };
+
+    CELLS.chars().position(|cell| cell == folded).map(|i| i as u8)
+}
+
+fn mask_to_char(mask: u8) -> char {
+    CELLS.as_bytes()[mask as usize] as char
+}
+
+fn mask_to_unicode(mask: u8) -> char {
+    std::char::from_u32(UNICODE_BASE + mask as u32).unwrap()
+}
+
+fn mask_to_dots(mask: u8) -> String {
+    if mask == 0 {
+        return "0".to_string();
+    }
+
+    (0..6)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| std::char::from_digit(bit + 1, 10).unwrap())
+        .collect()
+}
+
+fn dots_to_mask(dots: &str) -> Result<u8, String> {
+    if dots == "0" {
+        return Ok(0);
+    }
+
+    let mut mask = 0;
+
+    for d in dots.chars() {
+        match d.to_digit(10) {
+            Some(n) if (1..=6).contains(&n) => mask |= 1 << (n - 1),
+            _ => return Err(format!("{:?} isn't a valid set of braille dot numbers", dots)),
+        }
+    }
+
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod string_to_braille_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_braille_test_happy_path() {
+            assert_eq!(string_to_braille("abc 123", BrailleFormat::Unicode), Ok("⠁⠃⠉⠀⠂⠆⠒".to_string()));
+        }
+
+        #[test]
+        fn string_to_braille_test_dots() {
+            assert_eq!(string_to_braille("w z", BrailleFormat::Dots), Ok("2456 0 1356".to_string()));
+        }
+
+        #[test]
+        fn string_to_braille_test_folds_upper_range() {
+            assert_eq!(string_to_braille("{|}~`", BrailleFormat::Ascii), Ok("[\\]^@".to_string()));
+        }
+
+        #[test]
+        fn string_to_braille_test_unhappy_path() {
+            assert_eq!(string_to_braille("a\nb", BrailleFormat::Unicode), Err("the character '\\n' has no braille ascii equivalent".to_string()));
+        }
+    }
+
+    mod braille_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn braille_to_string_test_round_trip() {
+            let input: String = (32..=95u8).map(|d| d as char).collect();
+
+            for format in [BrailleFormat::Ascii, BrailleFormat::Unicode, BrailleFormat::Dots].iter() {
+                let braille = string_to_braille(&input, *format).unwrap();
+
+                assert_eq!(braille_to_string(&braille, *format), Ok(input.clone()));
+            }
+        }
+
+        #[test]
+        fn braille_to_string_test_unhappy_path_eight_dot() {
+            assert_eq!(braille_to_string("⡁", BrailleFormat::Unicode), Err("the character '⡁' isn't a six-dot braille pattern".to_string()));
+        }
+
+        #[test]
+        fn braille_to_string_test_unhappy_path_dots() {
+            assert_eq!(braille_to_string("127", BrailleFormat::Dots), Err("\"127\" isn't a valid set of braille dot numbers".to_string()));
+        }
+    }
+}
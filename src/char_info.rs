@@ -0,0 +1,240 @@
+//! Looking up what an ascii code is.
+//!
+//! [`char_info`] returns the name, abbreviation, class and the ways of escaping any of the 128
+//! ascii codes, for when it isn't obvious what a byte such as `0x1B` or `0x7F` is.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::char_info::*;
+//!
+//! let info = char_info(0x1B).unwrap();
+//!
+//! assert_eq!(info.name, "ESCAPE");
+//! assert_eq!(info.abbreviation, Some("ESC"));
+//! assert_eq!(info.caret.as_deref(), Some("^["));
+//! assert_eq!(info.class, CharClass::Control);
+//! assert_eq!(info.escapes.c, "\\x1b");
+//! ```
+
+use crate::ascii_table::{CONTROL_CODES, DELETE};
+use crate::{dec_to_hex_pairs, decimals_to_string};
+
+/// The Unicode names of the printable characters that aren't letters or digits, in order.
+const PUNCTUATION_NAMES: [(char, &str); 33] = [
+    (' ', "SPACE"), ('!', "EXCLAMATION MARK"), ('"', "QUOTATION MARK"), ('#', "NUMBER SIGN"),
+    ('$', "DOLLAR SIGN"), ('%', "PERCENT SIGN"), ('&', "AMPERSAND"), ('\'', "APOSTROPHE"),
+    ('(', "LEFT PARENTHESIS"), (')', "RIGHT PARENTHESIS"), ('*', "ASTERISK"), ('+', "PLUS SIGN"),
+    (',', "COMMA"), ('-', "HYPHEN-MINUS"), ('.', "FULL STOP"), ('/', "SOLIDUS"),
+    (':', "COLON"), (';', "SEMICOLON"), ('<', "LESS-THAN SIGN"), ('=', "EQUALS SIGN"),
+    ('>', "GREATER-THAN SIGN"), ('?', "QUESTION MARK"), ('@', "COMMERCIAL AT"), ('[', "LEFT SQUARE BRACKET"),
+    ('\\', "REVERSE SOLIDUS"), (']', "RIGHT SQUARE BRACKET"), ('^', "CIRCUMFLEX ACCENT"), ('_', "LOW LINE"),
+    ('`', "GRAVE ACCENT"), ('{', "LEFT CURLY BRACKET"), ('|', "VERTICAL LINE"), ('}', "RIGHT CURLY BRACKET"),
+    ('~', "TILDE"),
+];
+
+const DIGIT_NAMES: [&str; 10] = ["ZERO", "ONE", "TWO", "THREE", "FOUR", "FIVE", "SIX", "SEVEN", "EIGHT", "NINE"];
+
+const HTML_ENTITIES: [(char, &str); 5] = [('"', "&quot;"), ('&', "&amp;"), ('\'', "&apos;"), ('<', "&lt;"), ('>', "&gt;")];
+
+/// The classes an ascii code can belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    /// The control codes `0 - 31` and `127`, including tab and line feed.
+    Control,
+    /// The space character, `32`.
+    Space,
+    /// The digits `0 - 9`.
+    Digit,
+    /// The capital letters `A - Z`.
+    Upper,
+    /// The small letters `a - z`.
+    Lower,
+    /// Every other printable character.
+    Punctuation,
+}
+
+/// The ways an ascii code can be escaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Escapes {
+    /// The escape used in C string and character literals, such as `\n` or `\x1b`.
+    pub c: String,
+    /// The escape used in Rust string and character literals, such as `\n` or `\u{1b}`.
+    pub rust: String,
+    /// The Unicode code point, such as `U+001B`.
+    pub unicode: String,
+    /// The HTML named entity, or a numeric character reference such as `&#27;`.
+    pub html: String,
+    /// The URL percent-encoding, such as `%1B`. Unreserved characters are left as they are.
+    pub url: String,
+}
+
+/// Everything known about an ascii code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharInfo {
+    /// The code.
+    pub code: u8,
+    /// The character, for the printable codes `32 - 126`.
+    pub character: Option<char>,
+    /// The Unicode name of a printable character or the name of a control code, in capitals.
+    pub name: String,
+    /// The abbreviation of a control code or of the space, such as `ESC` or `SP`.
+    pub abbreviation: Option<&'static str>,
+    /// The caret notation of a control code, such as `^[` for escape.
+    pub caret: Option<String>,
+    /// The class of the code.
+    pub class: CharClass,
+    /// The ways of escaping the code.
+    pub escapes: Escapes,
+}
+
+/// This function returns the name, abbreviation, class and escapes of an ascii code.
+///
+/// If the code is above `127` an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::char_info::*;
+///
+/// let info = char_info(104).unwrap();
+///
+/// assert_eq!(info.character, Some('h'));
+/// assert_eq!(info.name, "LATIN SMALL LETTER H");
+/// assert_eq!(info.class, CharClass::Lower);
+///
+/// let info = char_info(127).unwrap();
+///
+/// assert_eq!(info.abbreviation, Some("DEL"));
+/// assert_eq!(info.caret.as_deref(), Some("^?"));
+///
+/// assert_eq!(char_info(200), Err("the number is outside the ascii range".to_string()));
+/// ```
+pub fn char_info(code: u8) -> Result<CharInfo, String> {
+    if code > 127 {
+        return Err("the number is outside the ascii range".to_string());
+    }
+
+    let c = code as char;
+    let character = decimals_to_string(&vec![code]).ok().and_then(|s| s.chars().next());
+
+    let class = match c {
+        ' ' => CharClass::Space,
+        '0'..='9' => CharClass::Digit,
+        'A'..='Z' => CharClass::Upper,
+        'a'..='z' => CharClass::Lower,
+        _ if c.is_ascii_control() => CharClass::Control,
+        _ => CharClass::Punctuation,
+    };
+
+    let control = match code {
+        0..=31 => Some(CONTROL_CODES[code as usize]),
+        127 => Some(DELETE),
+        _ => None,
+    };
+
+    let name = match (class, control) {
+        (_, Some((_, name))) => name.to_uppercase(),
+        (CharClass::Digit, _) => format!("DIGIT {}", DIGIT_NAMES[(code - b'0') as usize]),
+        (CharClass::Upper, _) => format!("LATIN CAPITAL LETTER {}", c),
+        (CharClass::Lower, _) => format!("LATIN SMALL LETTER {}", c.to_ascii_uppercase()),
+        _ => PUNCTUATION_NAMES.iter().find(|(p, _)| *p == c).map(|(_, name)| name.to_string()).unwrap_or_default(),
+    };
+
+    Ok(CharInfo {
+        code,
+        character,
+        name,
+        abbreviation: control.map(|(abbreviation, _)| abbreviation).or(if code == 32 { Some("SP") } else { None }),
+        caret: control.map(|_| format!("^{}", (code ^ 0x40) as char)),
+        class,
+        escapes: escapes(code),
+    })
+}
+
+fn escapes(code: u8) -> Escapes {
+    let c = code as char;
+    let hex = dec_to_hex_pairs(&[code]);
+
+    let c_escape = match c {
+        '\0' => "\\0".to_string(),
+        '\x07' => "\\a".to_string(),
+        '\x08' => "\\b".to_string(),
+        '\t' => "\\t".to_string(),
+        '\n' => "\\n".to_string(),
+        '\x0B' => "\\v".to_string(),
+        '\x0C' => "\\f".to_string(),
+        '\r' => "\\r".to_string(),
+        '\\' | '\'' | '"' => format!("\\{}", c),
+        _ if c.is_ascii_control() => format!("\\x{}", hex.to_lowercase()),
+        _ => c.to_string(),
+    };
+
+    let html = match HTML_ENTITIES.iter().find(|(e, _)| *e == c) {
+        Some((_, entity)) => entity.to_string(),
+        None => format!("&#{};", code),
+    };
+
+    let url = if c.is_ascii_alphanumeric() || "-._~".contains(c) { c.to_string() } else { format!("%{}", hex) };
+
+    Escapes {
+        c: c_escape,
+        rust: c.escape_default().to_string(),
+        unicode: format!("U+00{}", hex),
+        html,
+        url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod char_info_tests {
+        use super::super::*;
+
+        #[test]
+        fn char_info_test_every_code() {
+            for code in 0..=127u8 {
+                let info = char_info(code).unwrap();
+
+                assert_eq!(info.code, code);
+                assert!(!info.name.is_empty());
+                assert_eq!(info.character.is_some(), (32..=126).contains(&code));
+                assert_eq!(info.caret.is_some(), info.class == CharClass::Control);
+            }
+        }
+
+        #[test]
+        fn char_info_test_classes() {
+            let classes: Vec<CharClass> = b"\t 7Qq~".iter().map(|c| char_info(*c).unwrap().class).collect();
+
+            assert_eq!(classes, vec![CharClass::Control, CharClass::Space, CharClass::Digit, CharClass::Upper, CharClass::Lower, CharClass::Punctuation]);
+        }
+
+        #[test]
+        fn char_info_test_names() {
+            assert_eq!(char_info(0).unwrap().name, "NULL");
+            assert_eq!(char_info(32).unwrap().abbreviation, Some("SP"));
+            assert_eq!(char_info(b'5').unwrap().name, "DIGIT FIVE");
+            assert_eq!(char_info(b'Z').unwrap().name, "LATIN CAPITAL LETTER Z");
+            assert_eq!(char_info(b'\\').unwrap().name, "REVERSE SOLIDUS");
+            assert_eq!(char_info(0).unwrap().caret.as_deref(), Some("^@"));
+            assert_eq!(char_info(31).unwrap().caret.as_deref(), Some("^_"));
+        }
+
+        #[test]
+        fn char_info_test_escapes() {
+            assert_eq!(char_info(b'\n').unwrap().escapes, Escapes {
+                c: "\\n".to_string(),
+                rust: "\\n".to_string(),
+                unicode: "U+000A".to_string(),
+                html: "&#10;".to_string(),
+                url: "%0A".to_string(),
+            });
+
+            assert_eq!(char_info(b'<').unwrap().escapes.html, "&lt;");
+            assert_eq!(char_info(b'"').unwrap().escapes.c, "\\\"");
+            assert_eq!(char_info(127).unwrap().escapes.rust, "\\u{7f}");
+            assert_eq!(char_info(b' ').unwrap().escapes.url, "%20");
+            assert_eq!(char_info(b'~').unwrap().escapes.url, "~");
+        }
+    }
+}
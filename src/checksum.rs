@@ -0,0 +1,399 @@
+//! Checksums over byte vectors.
+//!
+//! Table-driven CRCs of any width from 8 to 32 bits are built from [`CrcParams`], with presets
+//! for the common variants. Adler-32, Fletcher-16, Fletcher-32 and the longitudinal redundancy
+//! check (LRC) are also supported.
+//!
+//! Every function returns a [`Checksum`], which can be shown in hexadecimal, decimal or binary.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::checksum::*;
+//!
+//! let sum = crc(b"123456789", &CRC_32).unwrap();
+//!
+//! assert_eq!(sum.value, 0xCBF43926);
+//! assert_eq!(sum.to_string(), "CBF43926");
+//! assert_eq!(sum.to_decimals(), vec![203, 244, 57, 38]);
+//! ```
+
+use std::fmt;
+
+use crate::{dec_to_bit, dec_to_hex};
+
+/// The parameters that describe a CRC algorithm, in the Rocksoft model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcParams {
+    /// The width of the CRC in bits, from `8` to `32`.
+    pub width: u8,
+    /// The generator polynomial, without its top bit.
+    pub poly: u32,
+    /// The starting value of the register.
+    pub init: u32,
+    /// Whether each input byte is processed least significant bit first.
+    pub reflect_in: bool,
+    /// Whether the final register is bit reversed before the XOR.
+    pub reflect_out: bool,
+    /// The value XORed with the final register.
+    pub xor_out: u32,
+}
+
+/// CRC-8 (CRC-8/SMBUS).
+pub const CRC_8: CrcParams = CrcParams { width: 8, poly: 0x07, init: 0x00, reflect_in: false, reflect_out: false, xor_out: 0x00 };
+
+/// CRC-8/MAXIM, used by 1-Wire devices.
+pub const CRC_8_MAXIM: CrcParams = CrcParams { width: 8, poly: 0x31, init: 0x00, reflect_in: true, reflect_out: true, xor_out: 0x00 };
+
+/// CRC-16/ARC, often just called CRC-16.
+pub const CRC_16_ARC: CrcParams = CrcParams { width: 16, poly: 0x8005, init: 0x0000, reflect_in: true, reflect_out: true, xor_out: 0x0000 };
+
+/// CRC-16/MODBUS, used by Modbus RTU.
+pub const CRC_16_MODBUS: CrcParams = CrcParams { width: 16, poly: 0x8005, init: 0xFFFF, reflect_in: true, reflect_out: true, xor_out: 0x0000 };
+
+/// CRC-16/CCITT-FALSE (CRC-16/IBM-3740).
+pub const CRC_16_CCITT_FALSE: CrcParams = CrcParams { width: 16, poly: 0x1021, init: 0xFFFF, reflect_in: false, reflect_out: false, xor_out: 0x0000 };
+
+/// CRC-16/XMODEM.
+pub const CRC_16_XMODEM: CrcParams = CrcParams { width: 16, poly: 0x1021, init: 0x0000, reflect_in: false, reflect_out: false, xor_out: 0x0000 };
+
+/// CRC-16/KERMIT.
+pub const CRC_16_KERMIT: CrcParams = CrcParams { width: 16, poly: 0x1021, init: 0x0000, reflect_in: true, reflect_out: true, xor_out: 0x0000 };
+
+/// CRC-32 as used by zip, PNG and Ethernet (CRC-32/ISO-HDLC).
+pub const CRC_32: CrcParams = CrcParams { width: 32, poly: 0x04C11DB7, init: 0xFFFFFFFF, reflect_in: true, reflect_out: true, xor_out: 0xFFFFFFFF };
+
+/// CRC-32C (Castagnoli), used by iSCSI and ext4.
+pub const CRC_32C: CrcParams = CrcParams { width: 32, poly: 0x1EDC6F41, init: 0xFFFFFFFF, reflect_in: true, reflect_out: true, xor_out: 0xFFFFFFFF };
+
+/// CRC-32/BZIP2.
+pub const CRC_32_BZIP2: CrcParams = CrcParams { width: 32, poly: 0x04C11DB7, init: 0xFFFFFFFF, reflect_in: false, reflect_out: false, xor_out: 0xFFFFFFFF };
+
+/// CRC-32/MPEG-2.
+pub const CRC_32_MPEG_2: CrcParams = CrcParams { width: 32, poly: 0x04C11DB7, init: 0xFFFFFFFF, reflect_in: false, reflect_out: false, xor_out: 0x00000000 };
+
+/// A checksum value and its width in bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checksum {
+    /// The checksum.
+    pub value: u32,
+    /// The width of the checksum in bits.
+    pub width: u8,
+}
+
+impl Checksum {
+    /// This function returns the checksum's bytes as decimals, most significant byte first.
+    ///
+    /// # Example
+    /// ```
+    /// use ascii_converter::checksum::*;
+    ///
+    /// assert_eq!(fletcher16(b"abcde").to_decimals(), vec![200, 240]);
+    /// ```
+    pub fn to_decimals(&self) -> Vec<u8> {
+        let bytes = (self.width as usize + 7) >> 3;
+
+        (0..bytes).rev().map(|i| (self.value >> (i * 8)) as u8).collect()
+    }
+
+    /// This function returns the checksum's bytes as two digit hexadecimal numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use ascii_converter::checksum::*;
+    ///
+    /// assert_eq!(fletcher16(b"abcde").to_hexadecimal(), vec!["C8", "F0"]);
+    /// ```
+    pub fn to_hexadecimal(&self) -> Vec<String> {
+        self.to_decimals().iter().map(|d| format!("{:0>2}", dec_to_hex(*d))).collect()
+    }
+
+    /// This function returns the checksum's bytes in the crate's binary form.
+    ///
+    /// # Example
+    /// ```
+    /// use ascii_converter::checksum::*;
+    ///
+    /// assert_eq!(fletcher16(b"abcde").to_binary(), vec![11001000, 11110000]);
+    /// ```
+    pub fn to_binary(&self) -> Vec<u32> {
+        self.to_decimals().into_iter().map(dec_to_bit).collect()
+    }
+}
+
+impl fmt::Display for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:0width$X}", self.value, width = (self.width as usize + 3) >> 2)
+    }
+}
+
+/// A table-driven CRC calculator built from a set of [`CrcParams`].
+///
+/// Building the table takes a little time, so keep a `Crc` around when checking many messages.
+#[derive(Debug, Clone)]
+pub struct Crc {
+    params: CrcParams,
+    table: [u32; 256],
+}
+
+impl Crc {
+    /// This function builds the lookup table for the CRC parameters passed in.
+    ///
+    /// If the width isn't between `8` and `32` an error will be thrown.
+    ///
+    /// # Example
+    /// ```
+    /// use ascii_converter::checksum::*;
+    ///
+    /// let crc16 = Crc::new(CRC_16_MODBUS).unwrap();
+    ///
+    /// assert_eq!(crc16.checksum(b"123456789").value, 0x4B37);
+    /// ```
+    pub fn new(params: CrcParams) -> Result<Crc, String> {
+        if params.width < 8 || params.width > 32 {
+            return Err("the CRC width must be between 8 and 32 bits".to_string());
+        }
+
+        let mask = mask(params.width);
+        let mut table = [0; 256];
+
+        for (i, entry) in table.iter_mut().enumerate() {
+            let mut crc = i as u32;
+
+            if params.reflect_in {
+                let poly = reflect(params.poly, params.width);
+
+                for _ in 0..8 {
+                    crc = if crc & 1 == 1 { (crc >> 1) ^ poly } else { crc >> 1 };
+                }
+            } else {
+                let top = 1 << (params.width - 1);
+
+                crc <<= params.width - 8;
+
+                for _ in 0..8 {
+                    crc = if crc & top != 0 { (crc << 1) ^ params.poly } else { crc << 1 };
+                }
+            }
+
+            *entry = crc & mask;
+        }
+
+        Ok(Crc { params, table })
+    }
+
+    /// This function returns the CRC of the data passed in.
+    pub fn checksum(&self, data: &[u8]) -> Checksum {
+        let params = &self.params;
+        let mask = mask(params.width);
+
+        let mut crc = if params.reflect_in { reflect(params.init, params.width) } else { params.init };
+
+        for byte in data.iter() {
+            crc = if params.reflect_in {
+                self.table[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+            } else {
+                self.table[(((crc >> (params.width - 8)) ^ *byte as u32) & 0xFF) as usize] ^ (crc << 8)
+            } & mask;
+        }
+
+        if params.reflect_in != params.reflect_out {
+            crc = reflect(crc, params.width);
+        }
+
+        Checksum { value: (crc ^ params.xor_out) & mask, width: params.width }
+    }
+}
+
+/// This function returns the CRC of the data using the parameters passed in.
+///
+/// To check many messages with the same parameters build a [`Crc`] once instead.
+///
+/// If the width of the parameters isn't between `8` and `32` an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::checksum::*;
+///
+/// assert_eq!(crc(b"123456789", &CRC_16_CCITT_FALSE).unwrap().to_string(), "29B1");
+/// ```
+pub fn crc(data: &[u8], params: &CrcParams) -> Result<Checksum, String> {
+    Ok(Crc::new(*params)?.checksum(data))
+}
+
+/// This function returns the Adler-32 checksum of the data, as used by zlib.
+///
+/// # Example
+/// ```
+/// use ascii_converter::checksum::*;
+///
+/// assert_eq!(adler32(b"Wikipedia").value, 0x11E60398);
+/// ```
+pub fn adler32(data: &[u8]) -> Checksum {
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+
+    for byte in data.iter() {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    Checksum { value: (b << 16) | a, width: 32 }
+}
+
+/// This function returns the Fletcher-16 checksum of the data.
+///
+/// # Example
+/// ```
+/// use ascii_converter::checksum::*;
+///
+/// assert_eq!(fletcher16(b"abcdef").value, 0x2057);
+/// ```
+pub fn fletcher16(data: &[u8]) -> Checksum {
+    let mut sum1: u32 = 0;
+    let mut sum2: u32 = 0;
+
+    for byte in data.iter() {
+        sum1 = (sum1 + *byte as u32) % 255;
+        sum2 = (sum2 + sum1) % 255;
+    }
+
+    Checksum { value: (sum2 << 8) | sum1, width: 16 }
+}
+
+/// This function returns the Fletcher-32 checksum of the data.
+///
+/// The data is read as little endian 16 bit words, an odd final byte is padded with a zero byte.
+///
+/// # Example
+/// ```
+/// use ascii_converter::checksum::*;
+///
+/// assert_eq!(fletcher32(b"abcde").value, 0xF04FC729);
+/// ```
+pub fn fletcher32(data: &[u8]) -> Checksum {
+    let mut sum1: u32 = 0;
+    let mut sum2: u32 = 0;
+
+    for word in data.chunks(2) {
+        let word = word[0] as u32 | (*word.get(1).unwrap_or(&0) as u32) << 8;
+
+        sum1 = (sum1 + word) % 65535;
+        sum2 = (sum2 + sum1) % 65535;
+    }
+
+    Checksum { value: (sum2 << 16) | sum1, width: 32 }
+}
+
+/// This function returns the longitudinal redundancy check of the data.
+///
+/// This is the two's complement of the sum of the bytes, so adding it to the sum gives zero.
+/// It is the checksum used by Modbus ASCII and Intel HEX.
+///
+/// # Example
+/// ```
+/// use ascii_converter::checksum::*;
+///
+/// assert_eq!(lrc(&[0x01, 0x03, 0x00, 0x6B, 0x00, 0x03]).value, 0x8E);
+/// ```
+pub fn lrc(data: &[u8]) -> Checksum {
+    let sum = data.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+
+    Checksum { value: sum.wrapping_neg() as u32, width: 8 }
+}
+
+fn mask(width: u8) -> u32 {
+    if width == 32 { u32::MAX } else { (1 << width) - 1 }
+}
+
+fn reflect(value: u32, width: u8) -> u32 {
+    value.reverse_bits() >> (32 - width)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod crc_tests {
+        use super::super::*;
+
+        #[test]
+        fn crc_test_check_values() {
+            let check = b"123456789";
+
+            assert_eq!(crc(check, &CRC_8).unwrap().value, 0xF4);
+            assert_eq!(crc(check, &CRC_8_MAXIM).unwrap().value, 0xA1);
+            assert_eq!(crc(check, &CRC_16_ARC).unwrap().value, 0xBB3D);
+            assert_eq!(crc(check, &CRC_16_MODBUS).unwrap().value, 0x4B37);
+            assert_eq!(crc(check, &CRC_16_CCITT_FALSE).unwrap().value, 0x29B1);
+            assert_eq!(crc(check, &CRC_16_XMODEM).unwrap().value, 0x31C3);
+            assert_eq!(crc(check, &CRC_16_KERMIT).unwrap().value, 0x2189);
+            assert_eq!(crc(check, &CRC_32).unwrap().value, 0xCBF43926);
+            assert_eq!(crc(check, &CRC_32C).unwrap().value, 0xE3069283);
+            assert_eq!(crc(check, &CRC_32_BZIP2).unwrap().value, 0xFC891918);
+            assert_eq!(crc(check, &CRC_32_MPEG_2).unwrap().value, 0x0376E6E7);
+        }
+
+        #[test]
+        fn crc_test_custom_width() {
+            // CRC-24/OPENPGP
+            let params = CrcParams { width: 24, poly: 0x864CFB, init: 0xB704CE, reflect_in: false, reflect_out: false, xor_out: 0 };
+
+            assert_eq!(crc(b"123456789", &params).unwrap().to_string(), "21CF02");
+        }
+
+        #[test]
+        fn crc_test_empty_data() {
+            assert_eq!(crc(&[], &CRC_32).unwrap().value, 0);
+            assert_eq!(crc(&[], &CRC_16_MODBUS).unwrap().value, 0xFFFF);
+        }
+
+        #[test]
+        fn crc_test_unhappy_path() {
+            let params = CrcParams { width: 5, ..CRC_8 };
+
+            assert_eq!(crc(b"123", &params), Err("the CRC width must be between 8 and 32 bits".to_string()));
+        }
+    }
+
+    mod fletcher_tests {
+        use super::super::*;
+
+        #[test]
+        fn fletcher16_test_happy_path() {
+            assert_eq!(fletcher16(b"abcde").value, 0xC8F0);
+            assert_eq!(fletcher16(b"abcdefgh").value, 0x0627);
+        }
+
+        #[test]
+        fn fletcher32_test_happy_path() {
+            assert_eq!(fletcher32(b"abcdef").value, 0x56502D2A);
+            assert_eq!(fletcher32(b"abcdefgh").value, 0xEBE19591);
+        }
+    }
+
+    mod checksum_tests {
+        use super::super::*;
+
+        #[test]
+        fn checksum_test_renderings() {
+            let sum = lrc(&[0x10, 0x20]);
+
+            assert_eq!(sum.to_string(), "D0");
+            assert_eq!(sum.to_hexadecimal(), vec!["D0".to_string()]);
+            assert_eq!(sum.to_decimals(), vec![208]);
+            assert_eq!(sum.to_binary(), vec![11010000]);
+        }
+
+        #[test]
+        fn checksum_test_leading_zeros() {
+            let sum = Checksum { value: 0x0627, width: 16 };
+
+            assert_eq!(sum.to_string(), "0627");
+            assert_eq!(sum.to_hexadecimal(), vec!["06".to_string(), "27".to_string()]);
+        }
+
+        #[test]
+        fn adler32_test_empty_data() {
+            assert_eq!(adler32(&[]).value, 1);
+        }
+    }
+}
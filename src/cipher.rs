@@ -0,0 +1,349 @@
+//! Classical ciphers over ascii text and decimals.
+//!
+//! Every cipher has a version taking text as a `&str` and a version taking the decimal form
+//! `&[u8]` used by the rest of the crate. Letters keep their case and, apart from ROT47,
+//! characters that aren't letters are passed through unchanged.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::cipher::*;
+//!
+//! assert_eq!(rot13("Hello, World!").unwrap(), "Uryyb, Jbeyq!");
+//! assert_eq!(vigenere_encrypt("ATTACK AT DAWN", "LEMON").unwrap(), "LXFOPV EF RNHR");
+//! ```
+
+use crate::string_to_decimals;
+
+/// This function shifts each letter `n` places along the alphabet (a Caesar cipher).
+///
+/// The shift wraps around so `z` shifted by `1` becomes `a`. Shifting by `26 - n` undoes the shift.
+///
+/// If the text contains a character that isn't ascii an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(rot_n("Zebra", 3).unwrap(), "Cheud");
+/// assert_eq!(rot_n("Cheud", 23).unwrap(), "Zebra");
+/// ```
+pub fn rot_n(txt: &str, n: u8) -> Result<String, String> {
+    let dec = rot_n_decimals(&string_to_decimals(txt)?, n)?;
+
+    Ok(to_text(&dec))
+}
+
+/// This function shifts each letter of the ascii decimals `n` places along the alphabet.
+///
+/// Values above `127` will cause an error to be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(rot_n_decimals(&[65, 122, 33], 1).unwrap(), vec![66, 97, 33]);
+/// ```
+pub fn rot_n_decimals(dec: &[u8], n: u8) -> Result<Vec<u8>, String> {
+    check_range(dec)?;
+
+    Ok(dec.iter().map(|d| shift_letter(*d, n % 26)).collect())
+}
+
+/// This function applies ROT13, shifting each letter 13 places.
+///
+/// As the alphabet has 26 letters applying ROT13 twice returns the original text.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(rot13("Uryyb").unwrap(), "Hello");
+/// ```
+pub fn rot13(txt: &str) -> Result<String, String> {
+    rot_n(txt, 13)
+}
+
+/// This function applies ROT47, rotating every printable character in the range `33 - 126`.
+///
+/// Spaces and control characters are passed through. Applying ROT47 twice returns the original text.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(rot47("Hello, World!").unwrap(), "w6==@[ (@C=5P");
+/// ```
+pub fn rot47(txt: &str) -> Result<String, String> {
+    let dec = rot47_decimals(&string_to_decimals(txt)?)?;
+
+    Ok(to_text(&dec))
+}
+
+/// This function applies ROT47 to ascii decimals.
+///
+/// Values above `127` will cause an error to be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(rot47_decimals(&[33, 126, 32]).unwrap(), vec![80, 79, 32]);
+/// ```
+pub fn rot47_decimals(dec: &[u8]) -> Result<Vec<u8>, String> {
+    check_range(dec)?;
+
+    Ok(dec.iter()
+        .map(|d| if (33..=126).contains(d) { 33 + (d - 33 + 47) % 94 } else { *d })
+        .collect())
+}
+
+/// This function applies the Atbash cipher, which reverses the alphabet so `a` becomes `z`.
+///
+/// Applying Atbash twice returns the original text.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(atbash("Wizard").unwrap(), "Draziw");
+/// ```
+pub fn atbash(txt: &str) -> Result<String, String> {
+    let dec = atbash_decimals(&string_to_decimals(txt)?)?;
+
+    Ok(to_text(&dec))
+}
+
+/// This function applies the Atbash cipher to ascii decimals.
+///
+/// Values above `127` will cause an error to be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(atbash_decimals(&[65, 98, 49]).unwrap(), vec![90, 121, 49]);
+/// ```
+pub fn atbash_decimals(dec: &[u8]) -> Result<Vec<u8>, String> {
+    check_range(dec)?;
+
+    Ok(dec.iter()
+        .map(|d| match letter_base(*d) {
+            Some(base) => base + 25 - (d - base),
+            None => *d,
+        })
+        .collect())
+}
+
+/// This function encrypts text with the Vigenère cipher.
+///
+/// Each letter is shifted by the matching letter of the key, where `a` is a shift of `0`.
+/// Characters that aren't letters are passed through and don't use up a letter of the key.
+///
+/// The key must be made of one or more letters, otherwise an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(vigenere_encrypt("Attack at dawn", "lemon").unwrap(), "Lxfopv ef rnhr");
+/// ```
+pub fn vigenere_encrypt(txt: &str, key: &str) -> Result<String, String> {
+    let dec = vigenere_encrypt_decimals(&string_to_decimals(txt)?, key)?;
+
+    Ok(to_text(&dec))
+}
+
+/// This function encrypts ascii decimals with the Vigenère cipher.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(vigenere_encrypt_decimals(&[65, 65, 65], "abc").unwrap(), vec![65, 66, 67]);
+/// ```
+pub fn vigenere_encrypt_decimals(dec: &[u8], key: &str) -> Result<Vec<u8>, String> {
+    vigenere(dec, key, false)
+}
+
+/// This function decrypts text that was encrypted with the Vigenère cipher.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(vigenere_decrypt("Lxfopv ef rnhr", "LEMON").unwrap(), "Attack at dawn");
+/// ```
+pub fn vigenere_decrypt(txt: &str, key: &str) -> Result<String, String> {
+    let dec = vigenere_decrypt_decimals(&string_to_decimals(txt)?, key)?;
+
+    Ok(to_text(&dec))
+}
+
+/// This function decrypts ascii decimals that were encrypted with the Vigenère cipher.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// assert_eq!(vigenere_decrypt_decimals(&[65, 66, 67], "abc").unwrap(), vec![65, 65, 65]);
+/// ```
+pub fn vigenere_decrypt_decimals(dec: &[u8], key: &str) -> Result<Vec<u8>, String> {
+    vigenere(dec, key, true)
+}
+
+/// This function lists every Caesar shift of the text passed in.
+///
+/// Returns a `Vec` of the shifts `1 - 25` paired with the text produced by shifting it that
+/// far, which is handy for spotting the plaintext of a Caesar cipher by eye.
+///
+/// # Example
+/// ```
+/// use ascii_converter::cipher::*;
+///
+/// let shifts = caesar_brute_force("Khoor").unwrap();
+///
+/// assert_eq!(shifts.len(), 25);
+/// assert_eq!(shifts[22], (23, "Hello".to_string()));
+/// ```
+pub fn caesar_brute_force(txt: &str) -> Result<Vec<(u8, String)>, String> {
+    let dec = string_to_decimals(txt)?;
+
+    let mut shifts = Vec::new();
+
+    for n in 1..26 {
+        shifts.push((n, to_text(&rot_n_decimals(&dec, n)?)));
+    }
+
+    Ok(shifts)
+}
+
+fn vigenere(dec: &[u8], key: &str, decrypt: bool) -> Result<Vec<u8>, String> {
+    check_range(dec)?;
+
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err("the key must only contain letters".to_string());
+    }
+
+    let shifts: Vec<u8> = key.bytes().map(|k| k.to_ascii_lowercase() - b'a').collect();
+    let mut key_index = 0;
+    let mut output = Vec::new();
+
+    for d in dec.iter() {
+        if letter_base(*d).is_some() {
+            let shift = shifts[key_index % shifts.len()];
+            let shift = if decrypt { (26 - shift) % 26 } else { shift };
+
+            output.push(shift_letter(*d, shift));
+            key_index += 1;
+        } else {
+            output.push(*d);
+        }
+    }
+
+    Ok(output)
+}
+
+fn letter_base(d: u8) -> Option<u8> {
+    match d {
+        b'A'..=b'Z' => Some(b'A'),
+        b'a'..=b'z' => Some(b'a'),
+        _ => None,
+    }
+}
+
+fn shift_letter(d: u8, n: u8) -> u8 {
+    match letter_base(d) {
+        Some(base) => base + (d - base + n) % 26,
+        None => d,
+    }
+}
+
+fn check_range(dec: &[u8]) -> Result<(), String> {
+    if dec.iter().any(|d| *d > 127) {
+        return Err("the number is outside the ascii range".to_string());
+    }
+
+    Ok(())
+}
+
+fn to_text(dec: &[u8]) -> String {
+    dec.iter().map(|d| *d as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod rot_n_tests {
+        use super::super::*;
+
+        #[test]
+        fn rot_n_test_happy_path() {
+            assert_eq!(rot_n("The quick brown fox!", 5), Ok("Ymj vznhp gwtbs ktc!".to_string()));
+        }
+
+        #[test]
+        fn rot_n_test_wraps_shift() {
+            assert_eq!(rot_n("abc", 27), rot_n("abc", 1));
+        }
+
+        #[test]
+        fn rot_n_test_unhappy_path() {
+            assert_eq!(rot_n("☢️", 3), Err("A character in the string isn't apart of the ascii table".to_string()));
+        }
+
+        #[test]
+        fn rot_n_decimals_test_unhappy_path() {
+            assert_eq!(rot_n_decimals(&[65, 200], 3), Err("the number is outside the ascii range".to_string()));
+        }
+    }
+
+    mod rot47_tests {
+        use super::super::*;
+
+        #[test]
+        fn rot47_test_round_trip() {
+            let input: String = (32..=126u8).map(|d| d as char).collect();
+
+            assert_eq!(rot47(&rot47(&input).unwrap()), Ok(input));
+        }
+    }
+
+    mod atbash_tests {
+        use super::super::*;
+
+        #[test]
+        fn atbash_test_happy_path() {
+            assert_eq!(atbash("abcxyz ABCXYZ 123"), Ok("zyxcba ZYXCBA 123".to_string()));
+        }
+    }
+
+    mod vigenere_tests {
+        use super::super::*;
+
+        #[test]
+        fn vigenere_test_round_trip() {
+            let input = "Meet me by the old oak tree at 10pm.";
+            let encrypted = vigenere_encrypt(input, "Secret").unwrap();
+
+            assert_eq!(vigenere_decrypt(&encrypted, "Secret"), Ok(input.to_string()));
+        }
+
+        #[test]
+        fn vigenere_test_unhappy_path_key() {
+            assert_eq!(vigenere_encrypt("text", "k3y"), Err("the key must only contain letters".to_string()));
+            assert_eq!(vigenere_encrypt("text", ""), Err("the key must only contain letters".to_string()));
+        }
+    }
+
+    mod caesar_brute_force_tests {
+        use super::super::*;
+
+        #[test]
+        fn caesar_brute_force_test_happy_path() {
+            let shifts = caesar_brute_force("Uryyb").unwrap();
+
+            assert_eq!(shifts.first(), Some(&(1, "Vszzc".to_string())));
+            assert!(shifts.contains(&(13, "Hello".to_string())));
+        }
+    }
+}
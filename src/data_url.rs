@@ -0,0 +1,298 @@
+//! Encoding and decoding RFC 2397 `data:` URLs.
+//!
+//! A data URL holds a small file inline, in the form `data:[<mediatype>][;base64],<data>`. The
+//! data is either base64 encoded or percent-encoded ascii text.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::data_url::*;
+//!
+//! let url = encode_data_url(b"Hello, World!", "text/plain", DataEncoding::Percent).unwrap();
+//!
+//! assert_eq!(url, "data:text/plain,Hello,%20World!");
+//!
+//! let decoded = decode_data_url(&url).unwrap();
+//!
+//! assert_eq!(decoded.media_type, "text/plain");
+//! assert_eq!(decoded.data, b"Hello, World!".to_vec());
+//! ```
+
+use crate::{dec_to_hex_pairs, hex_pairs_to_dec};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// How the data of a URL is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataEncoding {
+    /// Base64, marked with `;base64` before the comma.
+    Base64,
+    /// Percent-encoding, where bytes outside the safe ascii characters are written as `%XX`.
+    Percent,
+    /// Whichever of the two gives the shorter URL, preferring percent-encoding on a tie.
+    Shortest,
+}
+
+/// The parts of a decoded data URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataUrl {
+    /// The media type, such as `image/png`. This is `text/plain` if the URL doesn't give one.
+    pub media_type: String,
+    /// The parameters after the media type, such as `("charset", "UTF-8")`.
+    pub parameters: Vec<(String, String)>,
+    /// Whether the data was base64 encoded.
+    pub base64: bool,
+    /// The decoded data.
+    pub data: Vec<u8>,
+}
+
+/// This function encodes bytes and a media type into a data URL.
+///
+/// The media type is written as passed in, so it can include parameters such as
+/// `"text/plain;charset=UTF-8"`, or be empty to use the default of `text/plain;charset=US-ASCII`.
+///
+/// If the media type contains a character that isn't printable ascii, or contains a `,`, an
+/// error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::data_url::*;
+///
+/// assert_eq!(encode_data_url(&[0x89, 0x50, 0x4E, 0x47], "image/png", DataEncoding::Base64).unwrap(), "data:image/png;base64,iVBORw==");
+/// assert_eq!(encode_data_url(&[0x89, 0x50, 0x4E, 0x47], "", DataEncoding::Shortest).unwrap(), "data:,%89PNG");
+/// ```
+pub fn encode_data_url(data: &[u8], media_type: &str, encoding: DataEncoding) -> Result<String, String> {
+    if !media_type.chars().all(|c| c.is_ascii_graphic() || c == ' ') || media_type.contains(',') {
+        return Err(format!("{:?} isn't a valid media type", media_type));
+    }
+
+    let percent = format!("data:{},{}", media_type, percent_encode(data));
+    let base64 = format!("data:{};base64,{}", media_type, base64_encode(data));
+
+    Ok(match encoding {
+        DataEncoding::Base64 => base64,
+        DataEncoding::Percent => percent,
+        DataEncoding::Shortest if base64.len() < percent.len() => base64,
+        DataEncoding::Shortest => percent,
+    })
+}
+
+/// This function decodes a data URL into its media type, parameters and data.
+///
+/// Parameter values and the data are percent-decoded, and base64 data is then decoded, ignoring
+/// any whitespace. If the URL gives no media type the default of `text/plain;charset=US-ASCII`
+/// is used, or just `text/plain` if it gives parameters.
+///
+/// An error will be thrown if the URL doesn't start with `data:`, has no `,`, or its percent or
+/// base64 encoding isn't valid.
+///
+/// # Example
+/// ```
+/// use ascii_converter::data_url::*;
+///
+/// let decoded = decode_data_url("data:text/plain;charset=UTF-8;base64,Q3LDqG1l").unwrap();
+///
+/// assert_eq!(decoded.parameters, vec![("charset".to_string(), "UTF-8".to_string())]);
+/// assert_eq!(String::from_utf8(decoded.data).unwrap(), "Crème");
+///
+/// assert_eq!(decode_data_url("data:;base64,Q3L*"), Err("'*' isn't a base64 character".to_string()));
+/// ```
+pub fn decode_data_url(url: &str) -> Result<DataUrl, String> {
+    if !matches!(url.get(..5), Some(scheme) if scheme.eq_ignore_ascii_case("data:")) {
+        return Err("the URL doesn't start with \"data:\"".to_string());
+    }
+
+    let comma = url.find(',').ok_or_else(|| "the URL has no ',' before its data".to_string())?;
+    let mut header: Vec<&str> = url[5..comma].split(';').collect();
+
+    let base64 = header.len() > 1 && matches!(header.last(), Some(last) if last.eq_ignore_ascii_case("base64"));
+
+    if base64 {
+        header.pop();
+    }
+
+    let media_type = header.remove(0).trim().to_string();
+    let mut parameters = Vec::new();
+
+    for parameter in header.iter() {
+        let (name, value) = parameter.split_once('=')
+            .ok_or_else(|| format!("the parameter {:?} has no '='", parameter))?;
+
+        let value = String::from_utf8(percent_decode(value)?)
+            .map_err(|_| format!("the parameter {:?} isn't valid UTF-8", parameter))?;
+
+        parameters.push((name.trim().to_string(), value));
+    }
+
+    if media_type.is_empty() && parameters.is_empty() {
+        parameters.push(("charset".to_string(), "US-ASCII".to_string()));
+    }
+
+    let data = percent_decode(&url[comma + 1..])?;
+
+    Ok(DataUrl {
+        media_type: if media_type.is_empty() { "text/plain".to_string() } else { media_type },
+        parameters,
+        base64,
+        data: if base64 { base64_decode(&data)? } else { data },
+    })
+}
+
+fn percent_encode(data: &[u8]) -> String {
+    let mut encoded = String::new();
+
+    for byte in data.iter() {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/?".contains(byte) {
+            encoded.push(*byte as char);
+        } else {
+            encoded.push('%');
+            encoded.push_str(&dec_to_hex_pairs(&[*byte]));
+        }
+    }
+
+    encoded
+}
+
+fn percent_decode(text: &str) -> Result<Vec<u8>, String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = text.get(i + 1..i + 3).filter(|hex| hex.len() == 2)
+                .ok_or_else(|| "'%' must be followed by two hexadecimal digits".to_string())?;
+
+            decoded.extend(hex_pairs_to_dec(hex)?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    Ok(decoded)
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut encoded = String::new();
+
+    for chunk in data.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, b)| group | (*b as u32) << (16 - i * 8));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(group >> (18 - i * 6)) as usize & 0x3F] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+fn base64_decode(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut decoded = Vec::new();
+    let mut group: u32 = 0;
+    let mut bits = 0;
+    let mut padding = 0;
+
+    for byte in data.iter().filter(|b| !b.is_ascii_whitespace()) {
+        if *byte == b'=' {
+            padding += 1;
+            continue;
+        }
+
+        if padding > 0 {
+            return Err("base64 data continues after its padding".to_string());
+        }
+
+        let value = BASE64.iter().position(|c| c == byte)
+            .ok_or_else(|| format!("{:?} isn't a base64 character", *byte as char))?;
+
+        group = (group << 6) | value as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((group >> bits) as u8);
+        }
+    }
+
+    if bits >= 6 || padding > 2 {
+        return Err("the base64 data has an invalid length".to_string());
+    }
+
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod encode_data_url_tests {
+        use super::super::*;
+
+        #[test]
+        fn encode_data_url_test_base64_padding() {
+            assert_eq!(encode_data_url(b"", "", DataEncoding::Base64), Ok("data:;base64,".to_string()));
+            assert_eq!(encode_data_url(b"f", "", DataEncoding::Base64), Ok("data:;base64,Zg==".to_string()));
+            assert_eq!(encode_data_url(b"fo", "", DataEncoding::Base64), Ok("data:;base64,Zm8=".to_string()));
+            assert_eq!(encode_data_url(b"foo", "", DataEncoding::Base64), Ok("data:;base64,Zm9v".to_string()));
+            assert_eq!(encode_data_url(b"foobar", "", DataEncoding::Base64), Ok("data:;base64,Zm9vYmFy".to_string()));
+        }
+
+        #[test]
+        fn encode_data_url_test_round_trip() {
+            let data: Vec<u8> = (0..=255).collect();
+
+            for encoding in [DataEncoding::Base64, DataEncoding::Percent, DataEncoding::Shortest].iter() {
+                let url = encode_data_url(&data, "application/octet-stream", *encoding).unwrap();
+
+                assert_eq!(decode_data_url(&url).unwrap().data, data);
+            }
+        }
+
+        #[test]
+        fn encode_data_url_test_unhappy_path() {
+            assert_eq!(encode_data_url(b"", "text/plain,x", DataEncoding::Percent), Err("\"text/plain,x\" isn't a valid media type".to_string()));
+            assert_eq!(encode_data_url(b"", "text/plaïn", DataEncoding::Percent), Err("\"text/plaïn\" isn't a valid media type".to_string()));
+        }
+    }
+
+    mod decode_data_url_tests {
+        use super::super::*;
+
+        #[test]
+        fn decode_data_url_test_defaults() {
+            let decoded = decode_data_url("DATA:,A%20brief%20note").unwrap();
+
+            assert_eq!(decoded, DataUrl {
+                media_type: "text/plain".to_string(),
+                parameters: vec![("charset".to_string(), "US-ASCII".to_string())],
+                base64: false,
+                data: b"A brief note".to_vec(),
+            });
+
+            assert_eq!(decode_data_url("data:;charset=iso-8859-7,%be%fg%be").unwrap_err(), "invalid digit found in string");
+            assert_eq!(decode_data_url("data:;charset=iso-8859-7,%be%d3%be").unwrap().media_type, "text/plain");
+        }
+
+        #[test]
+        fn decode_data_url_test_base64_whitespace() {
+            assert_eq!(decode_data_url("data:image/gif;base64,R0lG\n ODlh").unwrap().data, b"GIF89a".to_vec());
+            assert_eq!(decode_data_url("data:;base64,Zg").unwrap().data, b"f".to_vec());
+        }
+
+        #[test]
+        fn decode_data_url_test_unhappy_path() {
+            assert_eq!(decode_data_url("http://example.com"), Err("the URL doesn't start with \"data:\"".to_string()));
+            assert_eq!(decode_data_url("dataé,x"), Err("the URL doesn't start with \"data:\"".to_string()));
+            assert_eq!(decode_data_url("daté,x"), Err("the URL doesn't start with \"data:\"".to_string()));
+            assert_eq!(decode_data_url("data:text/plain"), Err("the URL has no ',' before its data".to_string()));
+            assert_eq!(decode_data_url("data:text/plain;charset,hi"), Err("the parameter \"charset\" has no '='".to_string()));
+            assert_eq!(decode_data_url("data:,100%"), Err("'%' must be followed by two hexadecimal digits".to_string()));
+            assert_eq!(decode_data_url("data:;base64,Zg=a"), Err("base64 data continues after its padding".to_string()));
+            assert_eq!(decode_data_url("data:;base64,Z"), Err("the base64 data has an invalid length".to_string()));
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Conversion between EBCDIC and ascii using the common EBCDIC code pages.
+//!
+//! Mainframe data is usually encoded in EBCDIC rather than ascii. The code pages supported are
+//! listed in [`CodePage`], each one maps all 256 EBCDIC byte values to a character in the
+//! ISO-8859-1 (Latin-1) range, which includes the full ascii table.
+//!
+//! The hexadecimal and binary functions in this module show the EBCDIC side of the conversion,
+//! the ascii side can be shown with the functions at the root of the crate.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::ebcdic::*;
+//!
+//! let dump = vec!["C8".to_string(), "85".to_string(), "93".to_string(), "93".to_string(), "96".to_string()];
+//!
+//! assert_eq!(ebcdic_hexadecimal_to_string(&dump, CodePage::Cp037).unwrap(), "Hello");
+//! ```
+
+use crate::{bits_to_dec, dec_to_bit, dec_to_hex, hex_to_dec};
+
+/// The supported EBCDIC code pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodePage {
+    /// IBM code page 37, used in the USA, Canada and other English speaking regions.
+    Cp037,
+    /// IBM code page 500, the international Latin-1 code page.
+    Cp500,
+    /// IBM code page 1047, the Latin-1 code page used by z/OS Unix System Services.
+    Cp1047,
+}
+
+impl CodePage {
+    fn table(self) -> &'static [u8; 256] {
+        match self {
+            CodePage::Cp037 => &CP037,
+            CodePage::Cp500 => &CP500,
+            CodePage::Cp1047 => &CP1047,
+        }
+    }
+}
+
+/// This function converts ascii decimals into EBCDIC bytes.
+///
+/// Each value passed in should be in the ascii range `0 - 127`, otherwise an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// assert_eq!(ascii_to_ebcdic(&[65, 97, 48], CodePage::Cp500).unwrap(), vec![193, 129, 240]);
+/// ```
+pub fn ascii_to_ebcdic(dec: &[u8], code_page: CodePage) -> Result<Vec<u8>, String> {
+    let mut ebcdic = Vec::new();
+
+    for d in dec.iter() {
+        if *d > 127 {
+            return Err("the number is outside the ascii range".to_string());
+        }
+
+        ebcdic.push(encode(*d as char, code_page).unwrap());
+    }
+
+    Ok(ebcdic)
+}
+
+/// This function converts EBCDIC bytes into ascii decimals.
+///
+/// If a byte is a character that isn't part of the ascii table, such as `é`, an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// assert_eq!(ebcdic_to_ascii(&[193, 129, 240], CodePage::Cp500).unwrap(), vec![65, 97, 48]);
+/// ```
+pub fn ebcdic_to_ascii(ebcdic: &[u8], code_page: CodePage) -> Result<Vec<u8>, String> {
+    let mut dec = Vec::new();
+
+    for e in ebcdic.iter() {
+        let c = code_page.table()[*e as usize];
+
+        if c > 127 {
+            return Err(format!("the EBCDIC byte {} isn't an ascii character", dec_to_hex(*e)));
+        }
+
+        dec.push(c);
+    }
+
+    Ok(dec)
+}
+
+/// This function converts text into EBCDIC bytes.
+///
+/// Any character in the Latin-1 range can be converted, other characters will cause an error.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// assert_eq!(string_to_ebcdic("Héllo", CodePage::Cp037).unwrap(), vec![200, 81, 147, 147, 150]);
+/// ```
+pub fn string_to_ebcdic(txt: &str, code_page: CodePage) -> Result<Vec<u8>, String> {
+    let mut ebcdic = Vec::new();
+
+    for c in txt.chars() {
+        match encode(c, code_page) {
+            Some(e) => ebcdic.push(e),
+            None => return Err(format!("the character {:?} isn't in the EBCDIC code page", c)),
+        }
+    }
+
+    Ok(ebcdic)
+}
+
+/// This function converts EBCDIC bytes into text.
+///
+/// Every EBCDIC byte has a character so this conversion can't fail.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// assert_eq!(ebcdic_to_string(&[200, 81, 147, 147, 150], CodePage::Cp037), "Héllo");
+/// ```
+pub fn ebcdic_to_string(ebcdic: &[u8], code_page: CodePage) -> String {
+    ebcdic.iter()
+        .map(|e| code_page.table()[*e as usize] as char)
+        .collect()
+}
+
+/// This function returns the EBCDIC hexadecimal representation of the text passed in.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// let expected = vec!["C8".to_string(), "C9".to_string(), "5A".to_string()];
+///
+/// assert_eq!(string_to_ebcdic_hexadecimal("HI!", CodePage::Cp037).unwrap(), expected);
+/// ```
+pub fn string_to_ebcdic_hexadecimal(txt: &str, code_page: CodePage) -> Result<Vec<String>, String> {
+    Ok(string_to_ebcdic(txt, code_page)?.into_iter().map(dec_to_hex).collect())
+}
+
+/// This function decodes EBCDIC hexadecimal numbers, such as a mainframe dump, into text.
+///
+/// Any string passed in should be a valid hexadecimal number, if not an error will be returned.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// let dump = vec!["C8".to_string(), "C9".to_string(), "4F".to_string()];
+///
+/// assert_eq!(ebcdic_hexadecimal_to_string(&dump, CodePage::Cp037).unwrap(), "HI|");
+/// assert_eq!(ebcdic_hexadecimal_to_string(&dump, CodePage::Cp500).unwrap(), "HI!");
+/// ```
+pub fn ebcdic_hexadecimal_to_string(hex_vec: &[String], code_page: CodePage) -> Result<String, String> {
+    let mut ebcdic = Vec::new();
+
+    for h in hex_vec.iter() {
+        ebcdic.push(hex_to_dec(h)?);
+    }
+
+    Ok(ebcdic_to_string(&ebcdic, code_page))
+}
+
+/// This function returns the EBCDIC binary representation of the text passed in.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// assert_eq!(string_to_ebcdic_binary("HI", CodePage::Cp037).unwrap(), vec![11001000, 11001001]);
+/// ```
+pub fn string_to_ebcdic_binary(txt: &str, code_page: CodePage) -> Result<Vec<u32>, String> {
+    Ok(string_to_ebcdic(txt, code_page)?.into_iter().map(dec_to_bit).collect())
+}
+
+/// This function decodes EBCDIC binary numbers into text.
+///
+/// If a number passed in isn't binary or is larger than a byte an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::ebcdic::*;
+///
+/// assert_eq!(ebcdic_binary_to_string(&[11001000, 11001001], CodePage::Cp037).unwrap(), "HI");
+/// ```
+pub fn ebcdic_binary_to_string(bin_vec: &[u32], code_page: CodePage) -> Result<String, String> {
+    let mut ebcdic = Vec::new();
+
+    for b in bin_vec.iter() {
+        ebcdic.push(bits_to_dec(b)?);
+    }
+
+    Ok(ebcdic_to_string(&ebcdic, code_page))
+}
+
+fn encode(c: char, code_page: CodePage) -> Option<u8> {
+    if c as u32 > 255 {
+        return None;
+    }
+
+    code_page.table().iter().position(|t| *t as u32 == c as u32).map(|e| e as u8)
+}
+
+// Each table maps an EBCDIC byte to its Latin-1 character, one row per high nibble.
+const CP037: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0xA2, 0x2E, 0x3C, 0x28, 0x2B, 0x7C,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x21, 0x24, 0x2A, 0x29, 0x3B, 0xAC,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0xDD, 0xDE, 0xAE,
+    0x5E, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0x5B, 0x5D, 0xAF, 0xA8, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+const CP500: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0x5B, 0x2E, 0x3C, 0x28, 0x2B, 0x21,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x5D, 0x24, 0x2A, 0x29, 0x3B, 0x5E,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0xDD, 0xDE, 0xAE,
+    0xA2, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0xAC, 0x7C, 0xAF, 0xA8, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+const CP1047: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0xA2, 0x2E, 0x3C, 0x28, 0x2B, 0x7C,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x21, 0x24, 0x2A, 0x29, 0x3B, 0x5E,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0x5B, 0xDE, 0xAE,
+    0xAC, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0xDD, 0xA8, 0xAF, 0x5D, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+#[cfg(test)]
+mod tests {
+
+    mod ascii_to_ebcdic_tests {
+        use super::super::*;
+
+        #[test]
+        fn ascii_to_ebcdic_test_round_trip() {
+            let ascii: Vec<u8> = (0..=127).collect();
+
+            for code_page in [CodePage::Cp037, CodePage::Cp500, CodePage::Cp1047].iter() {
+                let ebcdic = ascii_to_ebcdic(&ascii, *code_page).unwrap();
+
+                assert_eq!(ebcdic_to_ascii(&ebcdic, *code_page), Ok(ascii.clone()));
+            }
+        }
+
+        #[test]
+        fn ascii_to_ebcdic_test_code_page_differences() {
+            let brackets = [b'[', b']', b'^'];
+
+            assert_eq!(ascii_to_ebcdic(&brackets, CodePage::Cp037), Ok(vec![0xBA, 0xBB, 0xB0]));
+            assert_eq!(ascii_to_ebcdic(&brackets, CodePage::Cp500), Ok(vec![0x4A, 0x5A, 0x5F]));
+            assert_eq!(ascii_to_ebcdic(&brackets, CodePage::Cp1047), Ok(vec![0xAD, 0xBD, 0x5F]));
+        }
+
+        #[test]
+        fn ascii_to_ebcdic_test_unhappy_path() {
+            assert_eq!(ascii_to_ebcdic(&[200], CodePage::Cp037), Err("the number is outside the ascii range".to_string()));
+        }
+    }
+
+    mod ebcdic_to_ascii_tests {
+        use super::super::*;
+
+        #[test]
+        fn ebcdic_to_ascii_test_unhappy_path() {
+            assert_eq!(ebcdic_to_ascii(&[0xC1, 0x51], CodePage::Cp037), Err("the EBCDIC byte 51 isn't an ascii character".to_string()));
+        }
+    }
+
+    mod string_to_ebcdic_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_ebcdic_test_unhappy_path() {
+            assert_eq!(string_to_ebcdic("€", CodePage::Cp037), Err("the character '€' isn't in the EBCDIC code page".to_string()));
+        }
+    }
+
+    mod ebcdic_hexadecimal_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn ebcdic_hexadecimal_to_string_test_happy_path() {
+            let input = string_to_ebcdic_hexadecimal("ACCOUNT-0042 £100", CodePage::Cp1047).unwrap();
+
+            assert_eq!(ebcdic_hexadecimal_to_string(&input, CodePage::Cp1047), Ok("ACCOUNT-0042 £100".to_string()));
+        }
+
+        #[test]
+        fn ebcdic_hexadecimal_to_string_test_unhappy_path() {
+            let input = vec!["C1".to_string(), "XY".to_string()];
+
+            assert_eq!(ebcdic_hexadecimal_to_string(&input, CodePage::Cp037), Err("invalid digit found in string".to_string()));
+        }
+    }
+
+    mod ebcdic_binary_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn ebcdic_binary_to_string_test_unhappy_path() {
+            assert_eq!(ebcdic_binary_to_string(&[12], CodePage::Cp037), Err("invalid digit found in string".to_string()));
+        }
+    }
+}
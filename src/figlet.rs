@@ -0,0 +1,474 @@
+//! Rendering text as large ascii art banners with FIGlet fonts.
+//!
+//! FIGlet fonts (`.flf` files) draw each character as a block of ascii art several lines high.
+//! When characters are placed next to each other they can be kept at their full width, moved
+//! together until they touch (kerning, which FIGlet calls fitting), or overlapped by one column
+//! where the touching characters can be merged (smushing) by the font's smushing rules.
+//!
+//! Two fonts are built in, [`FigletFont::block`] and [`FigletFont::term`], and any other font
+//! can be loaded with [`FigletFont::parse`]. Only horizontal layout is supported.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::figlet::*;
+//!
+//! let banner = FigletFont::block().render("Hi").unwrap();
+//!
+//! assert_eq!(banner, "#   # ###\n#   #  #\n#####  #\n#   #  #\n#   # ###");
+//! ```
+
+use std::collections::HashMap;
+
+const BLOCK: &str = include_str!("fonts/block.flf");
+const TERM: &str = include_str!("fonts/term.flf");
+
+const DEUTSCH: [char; 7] = ['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'];
+
+const EQUAL: u8 = 1;
+const LOWLINE: u8 = 2;
+const HIERARCHY: u8 = 4;
+const PAIR: u8 = 8;
+const BIG_X: u8 = 16;
+const HARDBLANK: u8 = 32;
+
+/// How characters are placed next to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Each character keeps its full width.
+    FullWidth,
+    /// Characters are moved together until they touch.
+    Kerning,
+    /// Characters are moved together until they overlap by one column, merging the overlapping
+    /// characters with the font's smushing rules.
+    Smushing,
+}
+
+/// A FIGlet font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FigletFont {
+    hardblank: char,
+    height: usize,
+    layout: Layout,
+    rules: u8,
+    glyphs: HashMap<char, Vec<Vec<char>>>,
+}
+
+impl FigletFont {
+    /// This function returns the built-in `block` font, five lines high and drawn with `#`.
+    ///
+    /// The font only has capital letters, lowercase letters are drawn the same way. It uses
+    /// kerning by default.
+    pub fn block() -> FigletFont {
+        FigletFont::parse(BLOCK).unwrap()
+    }
+
+    /// This function returns the built-in `term` font, where every character is drawn as itself.
+    ///
+    /// It uses full width by default, so the banner is the text unchanged.
+    pub fn term() -> FigletFont {
+        FigletFont::parse(TERM).unwrap()
+    }
+
+    /// This function parses the text of a FIGlet `.flf` font file.
+    ///
+    /// The characters `32 - 126` are read in order, followed by the German characters
+    /// `Ä Ö Ü ä ö ü ß` and any code tagged characters. A font may stop early after any whole
+    /// character, in which case the missing characters can't be rendered.
+    ///
+    /// An error will be thrown if the header isn't valid or the file ends part way through a character.
+    ///
+    /// # Example
+    /// ```
+    /// use ascii_converter::figlet::*;
+    ///
+    /// let font = FigletFont::parse("flf2a$ 1 1 4 -1 0\n$@@\n!!@@\n").unwrap();
+    ///
+    /// assert_eq!(font.height(), 1);
+    /// assert_eq!(font.render("! !").unwrap(), "!! !!");
+    ///
+    /// assert_eq!(FigletFont::parse("flf2a$ 2 1 4 -1 0\n$@@\n"), Err("the font ends part way through character ' '".to_string()));
+    /// ```
+    pub fn parse(text: &str) -> Result<FigletFont, String> {
+        let mut lines = text.lines();
+
+        let header: Vec<&str> = lines.next().unwrap_or("").split_whitespace().collect();
+
+        let hardblank = match header.first() {
+            Some(signature) if signature.starts_with("flf2a") => signature.chars().nth(5),
+            _ => None,
+        }
+        .ok_or_else(|| "the font doesn't start with a flf2a header".to_string())?;
+
+        let number = |i: usize| -> Result<Option<i32>, String> {
+            match header.get(i) {
+                Some(n) => n.parse().map(Some).map_err(|_| format!("the header value {:?} isn't a number", n)),
+                None => Ok(None),
+            }
+        };
+
+        let height = match number(1)? {
+            Some(h) if h > 0 => h as usize,
+            _ => return Err("the font height must be at least 1".to_string()),
+        };
+
+        let old_layout = number(4)?.ok_or_else(|| "the header has no layout".to_string())?;
+        let comment_lines = number(5)?.unwrap_or(0).max(0) as usize;
+
+        let (layout, rules) = match number(7)? {
+            Some(full) if full & 128 != 0 => (Layout::Smushing, (full & 63) as u8),
+            Some(full) if full & 64 != 0 => (Layout::Kerning, 0),
+            Some(_) => (Layout::FullWidth, 0),
+            None if old_layout < 0 => (Layout::FullWidth, 0),
+            None if old_layout == 0 => (Layout::Kerning, 0),
+            None => (Layout::Smushing, (old_layout & 63) as u8),
+        };
+
+        let mut lines = lines.skip(comment_lines).peekable();
+        let mut glyphs = HashMap::new();
+
+        let required = (32..=126u8).map(|c| c as char).chain(DEUTSCH.iter().copied());
+
+        for c in required {
+            if lines.peek().is_none() {
+                break;
+            }
+
+            glyphs.insert(c, read_glyph(&mut lines, height, c)?);
+        }
+
+        while let Some(tag) = lines.next() {
+            let code = tag.split_whitespace().next().unwrap_or("");
+            let c = parse_code(code).ok_or_else(|| format!("{:?} isn't a valid character code", code))?;
+            let glyph = read_glyph(&mut lines, height, c.unwrap_or(' '))?;
+
+            if let Some(c) = c {
+                glyphs.insert(c, glyph);
+            }
+        }
+
+        Ok(FigletFont { hardblank, height, layout, rules, glyphs })
+    }
+
+    /// This method returns the number of lines each character is drawn with.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// This method returns the layout the font uses by default.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// This method renders text as a banner using the font's default layout.
+    ///
+    /// See [`FigletFont::render_with_layout`].
+    pub fn render(&self, text: &str) -> Result<String, String> {
+        self.render_with_layout(text, self.layout)
+    }
+
+    /// This method renders text as a banner using the chosen layout.
+    ///
+    /// Each line of the text becomes a row of banner lines, the font's height tall. Trailing
+    /// spaces are removed from each banner line and the lines are joined with `\n`.
+    ///
+    /// If the text contains a character the font doesn't have an error will be thrown.
+    ///
+    /// # Example
+    /// ```
+    /// use ascii_converter::figlet::*;
+    ///
+    /// let font = FigletFont::block();
+    ///
+    /// assert_eq!(font.render_with_layout("-1", Layout::FullWidth).unwrap(), "     #\n    ##\n###  #\n     #\n    ###");
+    /// assert_eq!(font.render_with_layout("-1", Layout::Smushing).unwrap(), "    #\n   ##\n### #\n    #\n   ###");
+    ///
+    /// assert_eq!(font.render("☢"), Err("the font has no character '☢'".to_string()));
+    /// ```
+    pub fn render_with_layout(&self, text: &str, layout: Layout) -> Result<String, String> {
+        let mut banner = Vec::new();
+
+        for line in text.lines() {
+            let mut output: Vec<Vec<char>> = vec![Vec::new(); self.height];
+            let mut previous_width = 0;
+
+            for c in line.chars() {
+                let glyph = self.glyphs.get(&c).ok_or_else(|| format!("the font has no character {:?}", c))?;
+                let width = glyph[0].len();
+                let amount = self.smush_amount(&output, glyph, layout, previous_width);
+
+                for (row, glyph_row) in output.iter_mut().zip(glyph.iter()) {
+                    let length = row.len();
+
+                    for (k, right) in glyph_row.iter().take(amount).enumerate() {
+                        let left = row[length - amount + k];
+
+                        row[length - amount + k] = self.smush(left, *right, layout, previous_width, width).unwrap_or(*right);
+                    }
+
+                    row.extend_from_slice(&glyph_row[amount..]);
+                }
+
+                previous_width = width;
+            }
+
+            for row in output {
+                let row: String = row.into_iter().map(|c| if c == self.hardblank { ' ' } else { c }).collect();
+
+                banner.push(row.trim_end().to_string());
+            }
+        }
+
+        Ok(banner.join("\n"))
+    }
+
+    // The number of columns the glyph can move left into the output, as FIGlet works it out.
+    fn smush_amount(&self, output: &[Vec<char>], glyph: &[Vec<char>], layout: Layout, previous_width: usize) -> usize {
+        if layout == Layout::FullWidth || output[0].is_empty() {
+            return 0;
+        }
+
+        let width = glyph[0].len();
+        let mut amount = width;
+
+        for (row, glyph_row) in output.iter().zip(glyph.iter()) {
+            let line_end = row.iter().rposition(|c| *c != ' ');
+            let char_start = glyph_row.iter().position(|c| *c != ' ');
+
+            let mut row_amount = char_start.unwrap_or(width) + row.len() - 1 - line_end.unwrap_or(0);
+
+            match (line_end, char_start) {
+                (None, _) => row_amount += 1,
+                (Some(l), Some(c)) if self.smush(row[l], glyph_row[c], layout, previous_width, width).is_some() => row_amount += 1,
+                _ => {}
+            }
+
+            amount = amount.min(row_amount);
+        }
+
+        amount.min(output[0].len())
+    }
+
+    fn smush(&self, left: char, right: char, layout: Layout, previous_width: usize, width: usize) -> Option<char> {
+        if left == ' ' {
+            return Some(right);
+        }
+
+        if right == ' ' {
+            return Some(left);
+        }
+
+        if previous_width < 2 || width < 2 || layout != Layout::Smushing {
+            return None;
+        }
+
+        let hardblank = self.hardblank;
+
+        if self.rules == 0 {
+            return Some(if right == hardblank { left } else { right });
+        }
+
+        if self.rules & HARDBLANK != 0 && left == hardblank && right == hardblank {
+            return Some(left);
+        }
+
+        if left == hardblank || right == hardblank {
+            return None;
+        }
+
+        if self.rules & EQUAL != 0 && left == right {
+            return Some(left);
+        }
+
+        if self.rules & LOWLINE != 0 {
+            if left == '_' && "|/\\[]{}()<>".contains(right) {
+                return Some(right);
+            }
+
+            if right == '_' && "|/\\[]{}()<>".contains(left) {
+                return Some(left);
+            }
+        }
+
+        if self.rules & HIERARCHY != 0 {
+            let classes = ["|", "/\\", "[]", "{}", "()", "<>"];
+            let class = |c: char| classes.iter().position(|class| class.contains(c));
+
+            if let (Some(l), Some(r)) = (class(left), class(right)) {
+                if l != r {
+                    return Some(if l > r { left } else { right });
+                }
+            }
+        }
+
+        if self.rules & PAIR != 0 && ["[]", "][", "{}", "}{", "()", ")("].contains(&&*format!("{}{}", left, right)) {
+            return Some('|');
+        }
+
+        if self.rules & BIG_X != 0 {
+            match (left, right) {
+                ('/', '\\') => return Some('|'),
+                ('\\', '/') => return Some('Y'),
+                ('>', '<') => return Some('X'),
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+fn read_glyph<'a>(lines: &mut impl Iterator<Item = &'a str>, height: usize, c: char) -> Result<Vec<Vec<char>>, String> {
+    let mut glyph = Vec::new();
+
+    for _ in 0..height {
+        let line = lines.next().ok_or_else(|| format!("the font ends part way through character {:?}", c))?;
+        let line = line.trim_end();
+
+        let row = match line.chars().last() {
+            Some(end_mark) => line.trim_end_matches(end_mark),
+            None => line,
+        };
+
+        glyph.push(row.chars().collect::<Vec<char>>());
+    }
+
+    let width = glyph.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    for row in glyph.iter_mut() {
+        row.resize(width, ' ');
+    }
+
+    Ok(glyph)
+}
+
+// Reads a code tag in decimal, octal (leading 0) or hexadecimal (leading 0x). Negative codes
+// are valid but have no character, so they give `Some(None)`.
+fn parse_code(code: &str) -> Option<Option<char>> {
+    let (negative, digits) = match code.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, code),
+    };
+
+    let value = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()?
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        u32::from_str_radix(&digits[1..], 8).ok()?
+    } else {
+        digits.parse().ok()?
+    };
+
+    if negative {
+        Some(None)
+    } else {
+        std::char::from_u32(value).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    // A one line font of the characters ' ' to '/', with every smushing rule turned on.
+    pub const RULES_FONT: &str = "flf2a$ 1 1 4 63 1 0 191 0\n\
+                                  test font\n\
+                                  $$@\n|@\n\"\"@\n##@\na_@\n|b@\na/@\na[@\n\
+                                  (c@\n]c@\na$@\na\\@\n/b@\n--@\na.@\n//@\n";
+
+    // A full width font with every required character drawn as `x`, followed by the tags passed in.
+    pub fn tagged_font(tags: &str) -> String {
+        format!("flf2a$ 1 1 2 -1 0\n{}{}", "x@@\n".repeat(102), tags)
+    }
+
+    mod parse_tests {
+        use super::super::*;
+        use super::{tagged_font, RULES_FONT};
+
+        #[test]
+        fn parse_test_built_in_fonts() {
+            let block = FigletFont::block();
+            let term = FigletFont::term();
+
+            assert_eq!((block.height(), block.layout()), (5, Layout::Kerning));
+            assert_eq!((term.height(), term.layout()), (1, Layout::FullWidth));
+            assert_eq!(block.glyphs.len(), 102);
+            assert_eq!(term.render("Hello, World! @#$ Äß").unwrap(), "Hello, World! @#$ Äß");
+        }
+
+        #[test]
+        fn parse_test_layouts() {
+            let font = FigletFont::parse(RULES_FONT).unwrap();
+
+            assert_eq!((font.layout(), font.rules), (Layout::Smushing, 63));
+            assert_eq!(FigletFont::parse("flf2a$ 1 1 2 0 0").unwrap().layout(), Layout::Kerning);
+            assert_eq!(FigletFont::parse("flf2a$ 1 1 2 5 0").unwrap().rules, 5);
+            assert_eq!(FigletFont::parse("flf2a$ 1 1 2 5 0 0 64").unwrap().layout(), Layout::Kerning);
+        }
+
+        #[test]
+        fn parse_test_code_tagged_characters() {
+            let font = FigletFont::parse(&tagged_font("0x263A  WHITE SMILING FACE\n:)@@\n-1  unused\n?@@\n0101\nA@@\n")).unwrap();
+
+            assert_eq!(font.render("☺Ax").unwrap(), ":)Ax");
+        }
+
+        #[test]
+        fn parse_test_unhappy_path() {
+            assert_eq!(FigletFont::parse("tlf2a$ 1 1 2 -1 0"), Err("the font doesn't start with a flf2a header".to_string()));
+            assert_eq!(FigletFont::parse("flf2a$ 0 1 2 -1 0"), Err("the font height must be at least 1".to_string()));
+            assert_eq!(FigletFont::parse("flf2a$ 1 1 2 x 0"), Err("the header value \"x\" isn't a number".to_string()));
+            assert_eq!(FigletFont::parse("flf2a$ 1 1 2"), Err("the header has no layout".to_string()));
+            assert_eq!(FigletFont::parse(&tagged_font("zz\nx@@\n")), Err("\"zz\" isn't a valid character code".to_string()));
+            assert_eq!(FigletFont::parse(&tagged_font("200\n")), Err("the font ends part way through character 'È'".to_string()));
+        }
+    }
+
+    mod render_tests {
+        use super::super::*;
+        use super::RULES_FONT;
+
+        #[test]
+        fn render_test_smushing_rules() {
+            let font = FigletFont::parse(RULES_FONT).unwrap();
+
+            // equal characters
+            assert_eq!(font.render("##").unwrap(), "###");
+            // underscores give way to other characters
+            assert_eq!(font.render("$%").unwrap(), "a|b");
+            // the hierarchy of | / [ { ( < classes
+            assert_eq!(font.render("&(").unwrap(), "a(c");
+            // opposite pairs and the big X
+            assert_eq!(font.render("')").unwrap(), "a|c");
+            assert_eq!(font.render("+,").unwrap(), "aYb");
+            // hardblanks only smush with each other
+            assert_eq!(font.render("* *").unwrap(), "a  a");
+            // characters no rule covers are only kerned
+            assert_eq!(font.render(".-").unwrap(), "a.--");
+            // narrow characters are never smushed
+            assert_eq!(font.render("!!").unwrap(), "||");
+        }
+
+        #[test]
+        fn render_test_universal_smushing() {
+            let font = FigletFont::parse(&RULES_FONT.replace(" 63 1 0 191 ", " 63 1 0 128 ")).unwrap();
+
+            assert_eq!(font.render(".-").unwrap(), "a--");
+            assert_eq!(font.render("* *").unwrap(), "a a");
+        }
+
+        #[test]
+        fn render_test_layouts() {
+            let font = FigletFont::parse(RULES_FONT).unwrap();
+
+            assert_eq!(font.render_with_layout("--", Layout::FullWidth).unwrap(), "----");
+            assert_eq!(font.render_with_layout(" -", Layout::Kerning).unwrap(), "  --");
+            assert_eq!(font.render_with_layout("--", Layout::Smushing).unwrap(), "---");
+        }
+
+        #[test]
+        fn render_test_multiple_lines() {
+            let banner = FigletFont::block().render("A\nB").unwrap();
+
+            assert_eq!(banner.lines().count(), 10);
+            assert_eq!(FigletFont::term().render(""), Ok(String::new()));
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Reflected binary (Gray) code.
+//!
+//! In Gray code neighbouring values only differ by a single bit, which is why rotary encoders
+//! use it. The [`GrayCode`] trait converts the unsigned integer types, and the functions in this
+//! module convert the crate's binary form or decode Gray coded input when chosen with [`BinaryCoding`].
+//!
+//! # Example
+//! ```
+//! use ascii_converter::gray::*;
+//!
+//! assert_eq!(7u8.gray_encode(), 4);
+//! assert_eq!(4u8.gray_decode(), 7);
+//!
+//! assert_eq!(coded_binary_to_decimal(&[100], BinaryCoding::Gray).unwrap(), vec![7]);
+//! ```
+
+use crate::{decimals_to_string, dec_to_bit};
+
+/// Conversion of an unsigned integer to and from reflected binary Gray code.
+pub trait GrayCode {
+    /// This function returns the Gray code of the value.
+    fn gray_encode(self) -> Self;
+
+    /// This function returns the value that the Gray code represents.
+    fn gray_decode(self) -> Self;
+}
+
+macro_rules! impl_gray_code {
+    ($($t:ty),*) => {
+        $(
+            impl GrayCode for $t {
+                fn gray_encode(self) -> Self {
+                    self ^ (self >> 1)
+                }
+
+                fn gray_decode(self) -> Self {
+                    let mut value = self;
+                    let mut shift = 1;
+
+                    while shift < <$t>::BITS {
+                        value ^= value >> shift;
+                        shift <<= 1;
+                    }
+
+                    value
+                }
+            }
+        )*
+    };
+}
+
+impl_gray_code!(u8, u16, u32, u64);
+
+/// How the numbers in a binary vector are coded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryCoding {
+    /// Plain binary, as used by the rest of the crate.
+    Plain,
+    /// Reflected binary Gray code.
+    Gray,
+}
+
+/// This function converts binary numbers into their Gray codes.
+///
+/// Takes the crate's binary form, a `&[u32]` where each element is a binary byte, and returns
+/// the Gray code of each in the same form.
+///
+/// If a number passed in isn't a binary byte an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gray::*;
+///
+/// assert_eq!(binary_to_gray(&[111, 1000]).unwrap(), vec![100, 1100]);
+/// ```
+pub fn binary_to_gray(bin_vec: &[u32]) -> Result<Vec<u32>, String> {
+    let dec = crate::binary_to_decimal(&bin_vec.to_vec())?;
+
+    Ok(dec.iter().map(|d| dec_to_bit(d.gray_encode())).collect())
+}
+
+/// This function converts Gray codes into plain binary numbers.
+///
+/// If a number passed in isn't a binary byte an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gray::*;
+///
+/// assert_eq!(gray_to_binary(&[100, 1100]).unwrap(), vec![111, 1000]);
+/// ```
+pub fn gray_to_binary(bin_vec: &[u32]) -> Result<Vec<u32>, String> {
+    let dec = crate::binary_to_decimal(&bin_vec.to_vec())?;
+
+    Ok(dec.iter().map(|d| dec_to_bit(d.gray_decode())).collect())
+}
+
+/// This function takes in binary numbers coded as chosen and returns the decimal values.
+///
+/// With `BinaryCoding::Plain` this is the same as the crate's `binary_to_decimal`, with
+/// `BinaryCoding::Gray` each number is decoded from Gray code first.
+///
+/// If a number passed in isn't a binary byte an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gray::*;
+///
+/// let input = vec![1011100, 1010111, 1011010, 1011010, 1011000];
+///
+/// assert_eq!(coded_binary_to_decimal(&input, BinaryCoding::Gray).unwrap(), vec![104, 101, 108, 108, 111]);
+/// ```
+pub fn coded_binary_to_decimal(bin_vec: &[u32], coding: BinaryCoding) -> Result<Vec<u8>, String> {
+    let dec = crate::binary_to_decimal(&bin_vec.to_vec())?;
+
+    Ok(match coding {
+        BinaryCoding::Plain => dec,
+        BinaryCoding::Gray => dec.iter().map(|d| d.gray_decode()).collect(),
+    })
+}
+
+/// This function takes in binary numbers coded as chosen and returns the string they represent.
+///
+/// If a number isn't a binary byte or decodes to a value outside the ascii range `32 - 126`
+/// an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gray::*;
+///
+/// let input = vec![1011100, 1010111, 1011010, 1011010, 1011000];
+///
+/// assert_eq!(coded_binary_to_string(&input, BinaryCoding::Gray).unwrap(), "hello");
+/// ```
+pub fn coded_binary_to_string(bin_vec: &[u32], coding: BinaryCoding) -> Result<String, String> {
+    decimals_to_string(&coded_binary_to_decimal(bin_vec, coding)?)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod gray_code_tests {
+        use super::super::*;
+
+        #[test]
+        fn gray_code_test_neighbours_differ_by_one_bit() {
+            for n in 0..u16::MAX {
+                assert_eq!((n.gray_encode() ^ (n + 1).gray_encode()).count_ones(), 1);
+            }
+        }
+
+        #[test]
+        fn gray_code_test_round_trip() {
+            for n in 0..=u8::MAX {
+                assert_eq!(n.gray_encode().gray_decode(), n);
+            }
+
+            for n in [0u64, 1, 0xDEAD_BEEF, u64::MAX, 1 << 63].iter() {
+                assert_eq!(n.gray_encode().gray_decode(), *n);
+            }
+
+            assert_eq!(u32::MAX.gray_encode(), 1 << 31);
+        }
+    }
+
+    mod binary_to_gray_tests {
+        use super::super::*;
+
+        #[test]
+        fn binary_to_gray_test_round_trip() {
+            let input = vec![0, 1, 10, 11, 1101000, 11111111];
+
+            assert_eq!(gray_to_binary(&binary_to_gray(&input).unwrap()), Ok(input));
+        }
+
+        #[test]
+        fn binary_to_gray_test_unhappy_path() {
+            assert_eq!(binary_to_gray(&[102]), Err("number passed in isn't binary".to_string()));
+        }
+    }
+
+    mod coded_binary_to_decimal_tests {
+        use super::super::*;
+
+        #[test]
+        fn coded_binary_to_decimal_test_plain() {
+            let input = vec![1101000, 1100101];
+
+            assert_eq!(coded_binary_to_decimal(&input, BinaryCoding::Plain), crate::binary_to_decimal(&input));
+        }
+
+    }
+
+    mod coded_binary_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn coded_binary_to_string_test_unhappy_path() {
+            assert_eq!(coded_binary_to_string(&[1], BinaryCoding::Gray), Err("the number is outside the ascii range".to_string()));
+        }
+    }
+}
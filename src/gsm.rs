@@ -0,0 +1,336 @@
+//! The GSM 03.38 seven bit default alphabet and septet packing used in SMS messages.
+//!
+//! The GSM alphabet has 128 characters that only partly match ascii, for example `@` is `0`
+//! and `$` is `2`. Characters from the extension table, such as `€` and `{`, are written as
+//! the escape septet `27` followed by a second septet.
+//!
+//! In an SMS PDU the septets are packed into octets, least significant bit first. When the
+//! message has a user data header, fill bits are added so the first septet starts on a septet
+//! boundary, which is why the packing functions take a number of fill bits.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::gsm::*;
+//!
+//! assert_eq!(pdu_hex_to_string("E8329BFD4697D9EC37", 10, 0).unwrap(), "hellohello");
+//! assert_eq!(string_to_pdu_hex("hellohello", 0).unwrap(), "E8329BFD4697D9EC37");
+//! ```
+
+use crate::{dec_to_hex_pairs, hex_pairs_to_dec};
+
+/// The septet that switches to the extension table for the next septet.
+pub const ESCAPE: u8 = 0x1B;
+
+// The escape septet has no character of its own, receivers show it as a space.
+const DEFAULT_ALPHABET: [char; 128] = [
+    '@', '£', '$', '¥', 'è', 'é', 'ù', 'ì', 'ò', 'Ç', '\n', 'Ø', 'ø', '\r', 'Å', 'å',
+    'Δ', '_', 'Φ', 'Γ', 'Λ', 'Ω', 'Π', 'Ψ', 'Σ', 'Θ', 'Ξ', ' ', 'Æ', 'æ', 'ß', 'É',
+    ' ', '!', '"', '#', '¤', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?',
+    '¡', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ä', 'Ö', 'Ñ', 'Ü', '§',
+    '¿', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ñ', 'ü', 'à',
+];
+
+const EXTENSION_TABLE: [(u8, char); 10] = [
+    (0x0A, '\u{C}'), (0x14, '^'), (0x28, '{'), (0x29, '}'), (0x2F, '\\'),
+    (0x3C, '['), (0x3D, '~'), (0x3E, ']'), (0x40, '|'), (0x65, '€'),
+];
+
+/// This function converts text into GSM seven bit septets.
+///
+/// Characters from the extension table take two septets, the escape septet and the character's
+/// code. If a character isn't in the GSM alphabet an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gsm::*;
+///
+/// assert_eq!(string_to_gsm7("@5€").unwrap(), vec![0, 53, ESCAPE, 101]);
+/// ```
+pub fn string_to_gsm7(txt: &str) -> Result<Vec<u8>, String> {
+    let mut septets = Vec::new();
+
+    for c in txt.chars() {
+        let default = (0..128u8).find(|i| *i != ESCAPE && DEFAULT_ALPHABET[*i as usize] == c);
+
+        if let Some(i) = default {
+            septets.push(i);
+        } else if let Some((code, _)) = EXTENSION_TABLE.iter().find(|(_, e)| *e == c) {
+            septets.push(ESCAPE);
+            septets.push(*code);
+        } else {
+            return Err(format!("the character {:?} isn't in the GSM 7 bit alphabet", c));
+        }
+    }
+
+    Ok(septets)
+}
+
+/// This function converts GSM seven bit septets into text.
+///
+/// An escape septet followed by a code that isn't in the extension table is shown as the default
+/// alphabet character for that code, as the standard asks of receivers.
+///
+/// An error will be thrown if a septet is above `127` or the septets end with an escape.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gsm::*;
+///
+/// assert_eq!(gsm7_to_string(&[0, 53, ESCAPE, 101]).unwrap(), "@5€");
+/// ```
+pub fn gsm7_to_string(septets: &[u8]) -> Result<String, String> {
+    let mut text = String::new();
+    let mut iter = septets.iter();
+
+    while let Some(s) = iter.next() {
+        if *s > 127 {
+            return Err(format!("{} isn't a seven bit value", s));
+        }
+
+        if *s == ESCAPE {
+            let code = match iter.next() {
+                Some(code) if *code > 127 => return Err(format!("{} isn't a seven bit value", code)),
+                Some(code) => *code,
+                None => return Err("the septets end with an escape".to_string()),
+            };
+
+            match EXTENSION_TABLE.iter().find(|(c, _)| *c == code) {
+                Some((_, e)) => text.push(*e),
+                None => text.push(DEFAULT_ALPHABET[code as usize]),
+            }
+        } else {
+            text.push(DEFAULT_ALPHABET[*s as usize]);
+        }
+    }
+
+    Ok(text)
+}
+
+/// This function packs septets into octets.
+///
+/// The septets are written least significant bit first, after the number of zero fill bits
+/// passed in. Each septet should be in the range `0 - 127` and there can be at most `6` fill bits,
+/// otherwise an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gsm::*;
+///
+/// assert_eq!(pack_septets(&[104, 105], 0).unwrap(), vec![0xE8, 0x34]);
+/// ```
+pub fn pack_septets(septets: &[u8], fill_bits: u8) -> Result<Vec<u8>, String> {
+    check_fill_bits(fill_bits)?;
+
+    let mut octets = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits = fill_bits as u32;
+
+    for s in septets.iter() {
+        if *s > 127 {
+            return Err(format!("{} isn't a seven bit value", s));
+        }
+
+        buffer |= (*s as u32) << bits;
+        bits += 7;
+
+        while bits >= 8 {
+            octets.push(buffer as u8);
+            buffer >>= 8;
+            bits -= 8;
+        }
+    }
+
+    if bits > 0 {
+        octets.push(buffer as u8);
+    }
+
+    Ok(octets)
+}
+
+/// This function unpacks octets into septets.
+///
+/// The number of septets to read, the user data length of an SMS, has to be passed in as the
+/// unused bits of the last octet can look like an extra `@`. The fill bits at the start are skipped.
+///
+/// An error will be thrown if the octets are too short to hold that many septets.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gsm::*;
+///
+/// assert_eq!(unpack_septets(&[0xE8, 0x34], 2, 0).unwrap(), vec![104, 105]);
+/// ```
+pub fn unpack_septets(octets: &[u8], count: usize, fill_bits: u8) -> Result<Vec<u8>, String> {
+    check_fill_bits(fill_bits)?;
+
+    let bits = count.checked_mul(7).and_then(|bits| bits.checked_add(fill_bits as usize));
+
+    if bits.filter(|bits| *bits <= octets.len() * 8).is_none() {
+        return Err(format!("{} octets can't hold {} septets", octets.len(), count));
+    }
+
+    let mut septets = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    let mut skip = fill_bits as u32;
+
+    for o in octets.iter() {
+        buffer |= (*o as u32) << bits;
+        bits += 8;
+
+        if skip > 0 {
+            buffer >>= skip;
+            bits -= skip;
+            skip = 0;
+        }
+
+        while bits >= 7 && septets.len() < count {
+            septets.push((buffer & 0x7F) as u8);
+            buffer >>= 7;
+            bits -= 7;
+        }
+    }
+
+    Ok(septets)
+}
+
+/// This function converts text into a packed GSM 7 bit PDU payload written in hexadecimal.
+///
+/// The output is one string of two digit hexadecimal octets, as found in an SMS PDU.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gsm::*;
+///
+/// assert_eq!(string_to_pdu_hex("hi", 0).unwrap(), "E834");
+/// ```
+pub fn string_to_pdu_hex(txt: &str, fill_bits: u8) -> Result<String, String> {
+    let octets = pack_septets(&string_to_gsm7(txt)?, fill_bits)?;
+
+    Ok(dec_to_hex_pairs(&octets))
+}
+
+/// This function converts a packed GSM 7 bit PDU payload written in hexadecimal into text.
+///
+/// The hexadecimal string should contain two digits per octet, whitespace is ignored. The number
+/// of septets and fill bits are read from the PDU's user data length and header.
+///
+/// # Example
+/// ```
+/// use ascii_converter::gsm::*;
+///
+/// assert_eq!(pdu_hex_to_string("C8 32 9B FD 06", 5, 0).unwrap(), "Hello");
+/// ```
+pub fn pdu_hex_to_string(pdu: &str, septets: usize, fill_bits: u8) -> Result<String, String> {
+    let digits: String = pdu.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if digits.len() % 2 == 1 {
+        return Err("the PDU has an odd number of hexadecimal digits".to_string());
+    }
+
+    let octets = hex_pairs_to_dec(&digits)?;
+
+    gsm7_to_string(&unpack_septets(&octets, septets, fill_bits)?)
+}
+
+fn check_fill_bits(fill_bits: u8) -> Result<(), String> {
+    if fill_bits > 6 {
+        return Err("there can't be more than 6 fill bits".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod string_to_gsm7_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_gsm7_test_happy_path() {
+            assert_eq!(string_to_gsm7("£1 {ok}"), Ok(vec![1, 49, 32, ESCAPE, 0x28, 111, 107, ESCAPE, 0x29]));
+        }
+
+        #[test]
+        fn string_to_gsm7_test_unhappy_path() {
+            assert_eq!(string_to_gsm7("a`b"), Err("the character '`' isn't in the GSM 7 bit alphabet".to_string()));
+        }
+
+        #[test]
+        fn gsm7_to_string_test_round_trip() {
+            let input = "Ça coute 5€ [approx] ~ ¿ok? Ωß\r\n";
+
+            assert_eq!(gsm7_to_string(&string_to_gsm7(input).unwrap()), Ok(input.to_string()));
+        }
+
+        #[test]
+        fn gsm7_to_string_test_unknown_extension() {
+            assert_eq!(gsm7_to_string(&[ESCAPE, 65]), Ok("A".to_string()));
+        }
+
+        #[test]
+        fn gsm7_to_string_test_unhappy_path() {
+            assert_eq!(gsm7_to_string(&[65, ESCAPE]), Err("the septets end with an escape".to_string()));
+            assert_eq!(gsm7_to_string(&[128]), Err("128 isn't a seven bit value".to_string()));
+        }
+    }
+
+    mod pack_septets_tests {
+        use super::super::*;
+
+        #[test]
+        fn pack_septets_test_fill_bits() {
+            let septets = string_to_gsm7("Hello").unwrap();
+            let packed = pack_septets(&septets, 1).unwrap();
+
+            assert_eq!(packed, vec![0x90, 0x65, 0x36, 0xFB, 0x0D]);
+            assert_eq!(unpack_septets(&packed, 5, 1), Ok(septets));
+        }
+
+        #[test]
+        fn pack_septets_test_eight_septets() {
+            let septets: Vec<u8> = (0..8).map(|_| 127).collect();
+
+            assert_eq!(pack_septets(&septets, 0), Ok(vec![255; 7]));
+        }
+
+        #[test]
+        fn pack_septets_test_unhappy_path() {
+            assert_eq!(pack_septets(&[200], 0), Err("200 isn't a seven bit value".to_string()));
+            assert_eq!(pack_septets(&[1], 7), Err("there can't be more than 6 fill bits".to_string()));
+        }
+
+        #[test]
+        fn unpack_septets_test_unhappy_path() {
+            assert_eq!(unpack_septets(&[0xE8], 2, 0), Err("1 octets can't hold 2 septets".to_string()));
+        }
+
+        #[test]
+        fn unpack_septets_test_huge_count() {
+            assert_eq!(unpack_septets(&[0xE8], usize::MAX, 0), Err(format!("1 octets can't hold {} septets", usize::MAX)));
+            assert_eq!(unpack_septets(&[0xE8], usize::MAX / 7, 6), Err(format!("1 octets can't hold {} septets", usize::MAX / 7)));
+        }
+    }
+
+    mod pdu_hex_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn pdu_hex_to_string_test_seven_padding_bits() {
+            // Seven septets leave seven unused bits, which would otherwise decode as '@'.
+            let pdu = string_to_pdu_hex("abcdefg", 0).unwrap();
+
+            assert_eq!(pdu_hex_to_string(&pdu, 7, 0), Ok("abcdefg".to_string()));
+        }
+
+        #[test]
+        fn pdu_hex_to_string_test_unhappy_path() {
+            assert_eq!(pdu_hex_to_string("E83", 2, 0), Err("the PDU has an odd number of hexadecimal digits".to_string()));
+            assert_eq!(pdu_hex_to_string("E8ZZ", 2, 0), Err("invalid digit found in string".to_string()));
+        }
+    }
+}
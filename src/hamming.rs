@@ -0,0 +1,266 @@
+//! Hamming(7,4) and extended Hamming(8,4) error correcting codes.
+//!
+//! Each byte is split into two nibbles, high nibble first, and each nibble becomes one codeword.
+//! Codeword bits are numbered from `1` at the most significant end, in the order
+//! `p1 p2 d1 p3 d2 d3 d4`. The extended code adds an overall parity bit as bit `8`, at the least
+//! significant end, which lets it detect double bit errors (SECDED) as well as correct single ones.
+//!
+//! Codewords are held as `u8` values and can be shown in the crate's binary form.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::hamming::*;
+//!
+//! let mut codewords = encode(b"Hi", HammingCode::Hamming84);
+//!
+//! codewords[1] ^= 0b0001_0000;
+//!
+//! let decoded = decode(&codewords, HammingCode::Hamming84).unwrap();
+//!
+//! assert_eq!(decoded.data, b"Hi".to_vec());
+//! assert_eq!(decoded.corrected, vec![(1, 4)]);
+//! ```
+
+use crate::{binary_to_decimal, dec_to_bit, string_to_decimals};
+
+/// The Hamming codes that can be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HammingCode {
+    /// Seven bit codewords that correct a single bit error.
+    Hamming74,
+    /// Eight bit codewords that correct a single bit error and detect double bit errors.
+    Hamming84,
+}
+
+/// The result of decoding Hamming codewords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    /// The decoded bytes. Bytes made from an uncorrectable codeword can't be trusted.
+    pub data: Vec<u8>,
+    /// The single bit errors that were corrected, as the codeword index and the bit position.
+    pub corrected: Vec<(usize, u8)>,
+    /// The indices of codewords with a detected double bit error, only found by `Hamming84`.
+    pub uncorrectable: Vec<usize>,
+}
+
+/// This function encodes bytes into Hamming codewords, two codewords per byte.
+///
+/// # Example
+/// ```
+/// use ascii_converter::hamming::*;
+///
+/// assert_eq!(encode(&[0x68], HammingCode::Hamming74), vec![0b1100110, 0b1110000]);
+/// assert_eq!(encode(&[0x68], HammingCode::Hamming84), vec![0b11001100, 0b11100001]);
+/// ```
+pub fn encode(data: &[u8], code: HammingCode) -> Vec<u8> {
+    let mut codewords = Vec::new();
+
+    for byte in data.iter() {
+        for nibble in [byte >> 4, byte & 0xF].iter() {
+            let codeword = encode_nibble(*nibble);
+
+            codewords.push(match code {
+                HammingCode::Hamming74 => codeword,
+                HammingCode::Hamming84 => (codeword << 1) | (codeword.count_ones() % 2) as u8,
+            });
+        }
+    }
+
+    codewords
+}
+
+/// This function decodes Hamming codewords back into bytes, correcting single bit errors.
+///
+/// The corrected bit positions and any detected double bit errors are reported in the
+/// [`Decoded`] result. `Hamming74` can't tell a double bit error from a single one, so it will
+/// correct the wrong bit instead of reporting it.
+///
+/// An error will be thrown if there is an odd number of codewords or a codeword has more bits
+/// than the code uses.
+///
+/// # Example
+/// ```
+/// use ascii_converter::hamming::*;
+///
+/// let decoded = decode(&[0b11001100, 0b00100001], HammingCode::Hamming84).unwrap();
+///
+/// assert_eq!(decoded.uncorrectable, vec![1]);
+/// ```
+pub fn decode(codewords: &[u8], code: HammingCode) -> Result<Decoded, String> {
+    if codewords.len() % 2 == 1 {
+        return Err("there must be two codewords for every byte".to_string());
+    }
+
+    let mut nibbles = Vec::new();
+    let mut corrected = Vec::new();
+    let mut uncorrectable = Vec::new();
+
+    for (i, codeword) in codewords.iter().enumerate() {
+        let (codeword, overall_error) = match code {
+            HammingCode::Hamming74 if *codeword > 127 => return Err(format!("codeword {} has more than 7 bits", i)),
+            HammingCode::Hamming74 => (*codeword, false),
+            HammingCode::Hamming84 => (codeword >> 1, codeword.count_ones() % 2 == 1),
+        };
+
+        let syndrome = syndrome(codeword);
+
+        let codeword = match (syndrome, overall_error, code) {
+            (0, false, _) => codeword,
+            (0, true, _) => {
+                corrected.push((i, 8));
+                codeword
+            }
+            (_, false, HammingCode::Hamming84) => {
+                uncorrectable.push(i);
+                codeword
+            }
+            (position, _, _) => {
+                corrected.push((i, position));
+                codeword ^ (1 << (7 - position))
+            }
+        };
+
+        nibbles.push(decode_nibble(codeword));
+    }
+
+    Ok(Decoded {
+        data: nibbles.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect(),
+        corrected,
+        uncorrectable,
+    })
+}
+
+/// This function encodes a string into Hamming codewords shown in the crate's binary form.
+///
+/// If the string contains a character that isn't ascii an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::hamming::*;
+///
+/// assert_eq!(string_to_hamming_binary("h", HammingCode::Hamming74).unwrap(), vec![1100110, 1110000]);
+/// ```
+pub fn string_to_hamming_binary(txt: &str, code: HammingCode) -> Result<Vec<u32>, String> {
+    Ok(encode(&string_to_decimals(txt)?, code).into_iter().map(dec_to_bit).collect())
+}
+
+/// This function decodes Hamming codewords in the crate's binary form, correcting single bit errors.
+///
+/// If a number passed in isn't binary an error will be thrown, otherwise this works the same as [`decode`].
+///
+/// # Example
+/// ```
+/// use ascii_converter::hamming::*;
+///
+/// let decoded = hamming_binary_to_decimals(&[1100111, 1110000], HammingCode::Hamming74).unwrap();
+///
+/// assert_eq!(decoded.data, vec![104]);
+/// assert_eq!(decoded.corrected, vec![(0, 7)]);
+/// ```
+pub fn hamming_binary_to_decimals(bin_vec: &[u32], code: HammingCode) -> Result<Decoded, String> {
+    decode(&binary_to_decimal(&bin_vec.to_vec())?, code)
+}
+
+fn bit(value: u8, position: u8) -> u8 {
+    (value >> (7 - position)) & 1
+}
+
+fn encode_nibble(nibble: u8) -> u8 {
+    let d1 = (nibble >> 3) & 1;
+    let d2 = (nibble >> 2) & 1;
+    let d3 = (nibble >> 1) & 1;
+    let d4 = nibble & 1;
+
+    let p1 = d1 ^ d2 ^ d4;
+    let p2 = d1 ^ d3 ^ d4;
+    let p3 = d2 ^ d3 ^ d4;
+
+    (p1 << 6) | (p2 << 5) | (d1 << 4) | (p3 << 3) | (d2 << 2) | (d3 << 1) | d4
+}
+
+fn decode_nibble(codeword: u8) -> u8 {
+    (bit(codeword, 3) << 3) | (bit(codeword, 5) << 2) | (bit(codeword, 6) << 1) | bit(codeword, 7)
+}
+
+// The XOR of the positions of every set bit, which is the position of a single bit error.
+fn syndrome(codeword: u8) -> u8 {
+    (1..=7).filter(|p| bit(codeword, *p) == 1).fold(0, |s, p| s ^ p)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod encode_tests {
+        use super::super::*;
+
+        #[test]
+        fn encode_test_every_nibble_corrects_every_single_bit_error() {
+            for code in [HammingCode::Hamming74, HammingCode::Hamming84].iter() {
+                let bits = if *code == HammingCode::Hamming74 { 7 } else { 8 };
+
+                for byte in 0..=255u8 {
+                    let codewords = encode(&[byte], *code);
+
+                    for flip in 0..bits {
+                        let damaged = vec![codewords[0] ^ (1 << flip), codewords[1]];
+                        let decoded = decode(&damaged, *code).unwrap();
+
+                        assert_eq!(decoded.data, vec![byte]);
+                        assert_eq!(decoded.corrected, vec![(0, (bits - flip) as u8)]);
+                    }
+                }
+            }
+        }
+
+    }
+
+    mod string_to_hamming_binary_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_hamming_binary_test_unhappy_path() {
+            assert_eq!(string_to_hamming_binary("☢️", HammingCode::Hamming84), Err("A character in the string isn't apart of the ascii table".to_string()));
+        }
+    }
+
+    mod decode_tests {
+        use super::super::*;
+
+        #[test]
+        fn decode_test_round_trip() {
+            let data: Vec<u8> = (0..=255).collect();
+            let decoded = decode(&encode(&data, HammingCode::Hamming84), HammingCode::Hamming84).unwrap();
+
+            assert_eq!(decoded, Decoded { data, corrected: vec![], uncorrectable: vec![] });
+        }
+
+        #[test]
+        fn decode_test_detects_every_double_bit_error() {
+            let codewords = encode(b"A", HammingCode::Hamming84);
+
+            for a in 0..8 {
+                for b in (a + 1)..8 {
+                    let damaged = vec![codewords[0], codewords[1] ^ (1 << a) ^ (1 << b)];
+
+                    assert_eq!(decode(&damaged, HammingCode::Hamming84).unwrap().uncorrectable, vec![1]);
+                }
+            }
+        }
+
+        #[test]
+        fn decode_test_unhappy_path() {
+            assert_eq!(decode(&[0], HammingCode::Hamming74), Err("there must be two codewords for every byte".to_string()));
+            assert_eq!(decode(&[0, 128], HammingCode::Hamming74), Err("codeword 1 has more than 7 bits".to_string()));
+        }
+
+    }
+
+    mod hamming_binary_to_decimals_tests {
+        use super::super::*;
+
+        #[test]
+        fn hamming_binary_to_decimals_test_unhappy_path() {
+            assert_eq!(hamming_binary_to_decimals(&[2, 0], HammingCode::Hamming74), Err("number passed in isn't binary".to_string()));
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Reading and writing Intel HEX files.
+//!
+//! An Intel HEX file is made of ascii records such as `:0300300002337A1E`, each holding a byte
+//! count, a 16 bit address, a record type, the data and a checksum. Extended segment (`02`) and
+//! extended linear (`04`) address records move the 16 bit addresses into a larger address space.
+//!
+//! Parsing joins the data records into contiguous [`Segment`]s of memory, and writing splits
+//! segments back into records, adding extended linear address records where needed.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::intel_hex::*;
+//!
+//! let file = ":0B0010006164647265737320676170A7\n:00000001FF\n";
+//!
+//! let hex = parse_intel_hex(file).unwrap();
+//!
+//! assert_eq!(hex.segments[0].address, 0x0010);
+//! assert_eq!(hex.segments[0].data, b"address gap".to_vec());
+//! assert_eq!(write_intel_hex(&hex, 16).unwrap(), file);
+//! ```
+
+use crate::checksum::lrc;
+use crate::{dec_to_hex_pairs, hex_pairs_to_dec};
+
+const DATA: u8 = 0x00;
+const END_OF_FILE: u8 = 0x01;
+const EXTENDED_SEGMENT_ADDRESS: u8 = 0x02;
+const START_SEGMENT_ADDRESS: u8 = 0x03;
+const EXTENDED_LINEAR_ADDRESS: u8 = 0x04;
+const START_LINEAR_ADDRESS: u8 = 0x05;
+
+/// A contiguous block of memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    /// The address of the first byte.
+    pub address: u32,
+    /// The bytes stored from the address onwards.
+    pub data: Vec<u8>,
+}
+
+/// The address execution starts from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartAddress {
+    /// An 80x86 `CS:IP` pair, from a start segment address record.
+    Segment {
+        /// The code segment.
+        cs: u16,
+        /// The instruction pointer.
+        ip: u16,
+    },
+    /// A 32 bit `EIP` value, from a start linear address record.
+    Linear(u32),
+}
+
+/// The contents of an Intel HEX file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntelHex {
+    /// The memory segments, in the order they appear in the file.
+    pub segments: Vec<Segment>,
+    /// The start address, if the file has one.
+    pub start_address: Option<StartAddress>,
+}
+
+/// This function parses the text of an Intel HEX file.
+///
+/// Each record's checksum is checked and data records that follow on from each other are joined
+/// into a single segment. Blank lines are ignored, and the file must finish with an end of file record.
+///
+/// Any error is returned with the line number it was found on.
+///
+/// # Example
+/// ```
+/// use ascii_converter::intel_hex::*;
+///
+/// let file = ":020000040800F2\n:0400000001020304F2\n:0400000508000000EF\n:00000001FF\n";
+///
+/// let hex = parse_intel_hex(file).unwrap();
+///
+/// assert_eq!(hex.segments, vec![Segment { address: 0x0800_0000, data: vec![1, 2, 3, 4] }]);
+/// assert_eq!(hex.start_address, Some(StartAddress::Linear(0x0800_0000)));
+///
+/// assert_eq!(parse_intel_hex(":0400000001020304F3\n"), Err("line 1: checksum is F3 but should be F2".to_string()));
+/// ```
+pub fn parse_intel_hex(text: &str) -> Result<IntelHex, String> {
+    let mut hex = IntelHex::default();
+    let mut base: u32 = 0;
+    let mut finished = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if finished {
+            return Err(format!("line {}: record after the end of file record", i + 1));
+        }
+
+        let (record_type, address, data) = parse_record(line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+
+        match record_type {
+            DATA => add_data(&mut hex.segments, base.wrapping_add(address as u32), data),
+            END_OF_FILE => finished = true,
+            EXTENDED_SEGMENT_ADDRESS | EXTENDED_LINEAR_ADDRESS if data.len() != 2 => {
+                return Err(format!("line {}: address records must have 2 data bytes", i + 1));
+            }
+            EXTENDED_SEGMENT_ADDRESS => base = u16::from_be_bytes([data[0], data[1]]) as u32 * 16,
+            EXTENDED_LINEAR_ADDRESS => base = (u16::from_be_bytes([data[0], data[1]]) as u32) << 16,
+            START_SEGMENT_ADDRESS | START_LINEAR_ADDRESS if data.len() != 4 => {
+                return Err(format!("line {}: start address records must have 4 data bytes", i + 1));
+            }
+            START_SEGMENT_ADDRESS => {
+                hex.start_address = Some(StartAddress::Segment {
+                    cs: u16::from_be_bytes([data[0], data[1]]),
+                    ip: u16::from_be_bytes([data[2], data[3]]),
+                });
+            }
+            START_LINEAR_ADDRESS => {
+                hex.start_address = Some(StartAddress::Linear(u32::from_be_bytes([data[0], data[1], data[2], data[3]])));
+            }
+            t => return Err(format!("line {}: unknown record type {:02X}", i + 1, t)),
+        }
+    }
+
+    if !finished {
+        return Err("the file has no end of file record".to_string());
+    }
+
+    Ok(hex)
+}
+
+/// This function writes segments out as the text of an Intel HEX file.
+///
+/// Each data record holds up to `record_size` bytes. Extended linear address records are added
+/// whenever the upper 16 bits of the address change, and records never cross a 64K boundary.
+/// The start address, if any, is written before the end of file record.
+///
+/// If `record_size` is `0` an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::intel_hex::*;
+///
+/// let hex = IntelHex {
+///     segments: vec![Segment { address: 0x0100, data: vec![0x21, 0x46, 0x01, 0x36] }],
+///     start_address: None,
+/// };
+///
+/// assert_eq!(write_intel_hex(&hex, 2).unwrap(), ":02010000214696\n:020102000136C4\n:00000001FF\n");
+/// ```
+pub fn write_intel_hex(hex: &IntelHex, record_size: u8) -> Result<String, String> {
+    if record_size == 0 {
+        return Err("the record size must be at least 1 byte".to_string());
+    }
+
+    let mut text = String::new();
+    let mut upper: u32 = 0;
+
+    for segment in hex.segments.iter() {
+        let mut address = segment.address;
+        let mut data = &segment.data[..];
+
+        while !data.is_empty() {
+            if address >> 16 != upper {
+                upper = address >> 16;
+                text.push_str(&record(EXTENDED_LINEAR_ADDRESS, 0, &(upper as u16).to_be_bytes()));
+            }
+
+            let to_boundary = 0x10000 - (address & 0xFFFF) as usize;
+            let length = data.len().min(record_size as usize).min(to_boundary);
+
+            text.push_str(&record(DATA, address as u16, &data[..length]));
+
+            address = address.wrapping_add(length as u32);
+            data = &data[length..];
+        }
+    }
+
+    match hex.start_address {
+        Some(StartAddress::Segment { cs, ip }) => {
+            text.push_str(&record(START_SEGMENT_ADDRESS, 0, &[cs.to_be_bytes(), ip.to_be_bytes()].concat()));
+        }
+        Some(StartAddress::Linear(eip)) => text.push_str(&record(START_LINEAR_ADDRESS, 0, &eip.to_be_bytes())),
+        None => {}
+    }
+
+    text.push_str(&record(END_OF_FILE, 0, &[]));
+
+    Ok(text)
+}
+
+fn parse_record(line: &str) -> Result<(u8, u16, Vec<u8>), String> {
+    if !line.starts_with(':') {
+        return Err("record doesn't start with ':'".to_string());
+    }
+
+    let bytes = hex_pairs_to_dec(&line[1..])?;
+
+    if bytes.len() < 5 {
+        return Err("record is too short".to_string());
+    }
+
+    let length = bytes[0] as usize;
+
+    if bytes.len() != length + 5 {
+        return Err(format!("record has {} data bytes but its byte count is {}", bytes.len() - 5, length));
+    }
+
+    let (body, checksum) = bytes.split_at(bytes.len() - 1);
+    let expected = lrc(body).value as u8;
+
+    if checksum[0] != expected {
+        return Err(format!("checksum is {:02X} but should be {:02X}", checksum[0], expected));
+    }
+
+    Ok((bytes[3], u16::from_be_bytes([bytes[1], bytes[2]]), body[4..].to_vec()))
+}
+
+fn record(record_type: u8, address: u16, data: &[u8]) -> String {
+    let mut bytes = vec![data.len() as u8];
+
+    bytes.extend_from_slice(&address.to_be_bytes());
+    bytes.push(record_type);
+    bytes.extend_from_slice(data);
+    bytes.push(lrc(&bytes).value as u8);
+
+    format!(":{}\n", dec_to_hex_pairs(&bytes))
+}
+
+pub(crate) fn add_data(segments: &mut Vec<Segment>, address: u32, data: Vec<u8>) {
+    if let Some(last) = segments.last_mut() {
+        if last.address.wrapping_add(last.data.len() as u32) == address {
+            last.data.extend(data);
+            return;
+        }
+    }
+
+    segments.push(Segment { address, data });
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod parse_intel_hex_tests {
+        use super::super::*;
+
+        #[test]
+        fn parse_intel_hex_test_joins_contiguous_records() {
+            let file = ":10010000214601360121470136007EFE09D2190140\n\
+                        :100110002146017E17C20001FF5F16002148011928\n\
+                        :10012000194E79234623965778239EDA3F01B2CAA7\n\
+                        :100130003F0156702B5E712B722B732146013421C7\n\
+                        :00000001FF\n";
+
+            let hex = parse_intel_hex(file).unwrap();
+
+            assert_eq!(hex.segments.len(), 1);
+            assert_eq!(hex.segments[0].address, 0x0100);
+            assert_eq!(hex.segments[0].data.len(), 64);
+            assert_eq!(hex.start_address, None);
+        }
+
+        #[test]
+        fn parse_intel_hex_test_extended_segment_address() {
+            let file = ":020000021200EA\n:0100000055AA\n:0400000312340010A3\n:00000001FF\n";
+            let hex = parse_intel_hex(file).unwrap();
+
+            assert_eq!(hex.segments, vec![Segment { address: 0x12000, data: vec![0x55] }]);
+            assert_eq!(hex.start_address, Some(StartAddress::Segment { cs: 0x1234, ip: 0x0010 }));
+        }
+
+        #[test]
+        fn parse_intel_hex_test_unhappy_path() {
+            assert_eq!(parse_intel_hex("\n0100000055AA\n"), Err("line 2: record doesn't start with ':'".to_string()));
+            assert_eq!(parse_intel_hex(":010000005\n"), Err("line 1: the hexadecimal string has an odd number of digits".to_string()));
+            assert_eq!(parse_intel_hex(":0200000055A9\n"), Err("line 1: record has 1 data bytes but its byte count is 2".to_string()));
+            assert_eq!(parse_intel_hex(":0100000655A4\n"), Err("line 1: unknown record type 06".to_string()));
+            assert_eq!(parse_intel_hex(":0100000055AA\n"), Err("the file has no end of file record".to_string()));
+            assert_eq!(parse_intel_hex(":00000001FF\n:0100000055AA\n"), Err("line 2: record after the end of file record".to_string()));
+        }
+    }
+
+    mod write_intel_hex_tests {
+        use super::super::*;
+
+        #[test]
+        fn write_intel_hex_test_round_trip() {
+            let hex = IntelHex {
+                segments: vec![
+                    Segment { address: 0x0000_FFF0, data: (0..40).collect() },
+                    Segment { address: 0x0802_0000, data: b"firmware".to_vec() },
+                ],
+                start_address: Some(StartAddress::Linear(0x0802_0000)),
+            };
+
+            let text = write_intel_hex(&hex, 16).unwrap();
+
+            assert!(text.contains(":020000040001F9\n"));
+            assert!(text.contains(":020000040802F0\n"));
+            assert_eq!(parse_intel_hex(&text), Ok(hex));
+        }
+
+        #[test]
+        fn write_intel_hex_test_unhappy_path() {
+            assert_eq!(write_intel_hex(&IntelHex::default(), 0), Err("the record size must be at least 1 byte".to_string()));
+        }
+    }
+}
@@ -15,6 +15,60 @@
 //! - Hexadecimals = `Vec<String>`.
 //! 
 //! ---
+//!
+//! Other encodings of ascii text are grouped into their own modules:
+//!
+//! - [`morse`] - International Morse code.
+//!
+//! - [`phonetic`] - ICAO (NATO) phonetic spelling.
+//!
+//! - [`braille`] - North American Braille ASCII and Unicode Braille patterns.
+//!
+//! - [`cipher`] - Caesar, ROT13, ROT47, Atbash and Vigenère ciphers.
+//!
+//! - [`xor`] - Repeating-key XOR and single byte key brute forcing.
+//!
+//! - [`ebcdic`] - EBCDIC code pages 037, 500 and 1047.
+//!
+//! - [`baudot`] - Five bit Baudot (ITA2 and US-TTY) codes.
+//!
+//! - [`sixbit`] - DEC SIXBIT and six bit ascii packing.
+//!
+//! - [`gsm`] - The GSM 03.38 seven bit alphabet and SMS septet packing.
+//!
+//! - [`bcd`] - Binary-coded decimal and packed BCD.
+//!
+//! - [`gray`] - Reflected binary Gray code.
+//!
+//! - [`parity`] - Even, odd, mark and space parity bits for seven bit ascii.
+//!
+//! - [`hamming`] - Hamming(7,4) and extended Hamming(8,4) error correction.
+//!
+//! - [`checksum`] - CRC, Adler-32, Fletcher and LRC checksums.
+//!
+//! - [`intel_hex`] - Intel HEX firmware files.
+//!
+//! - [`srecord`] - Motorola S-record firmware files.
+//!
+//! - [`modbus`] - Modbus ASCII frames.
+//!
+//! - [`nmea`] - NMEA 0183 sentences and checksums.
+//!
+//! - [`source_array`] - Byte array literals for C, Rust and Python source code.
+//!
+//! - [`data_url`] - RFC 2397 `data:` URLs.
+//!
+//! - [`punycode`] - Punycode and `xn--` domain name labels.
+//!
+//! - [`transliterate`] - Transliteration of Unicode text into ascii.
+//!
+//! - [`figlet`] - Ascii art banners from FIGlet fonts.
+//!
+//! - [`ascii_table`] - A reference table of the ascii codes, like `man ascii`.
+//!
+//! - [`char_info`] - The name, class and escapes of an ascii code.
+//!
+//! ---
 //! 
 //! Each method uses the `Result` enum for the return type so this will need to be unwrapped to get the actual value.
 //! An example of how best to deal with the Results enum is below:
@@ -29,6 +83,32 @@
 //! };
 //! ```
 
+pub mod ascii_table;
+pub mod baudot;
+pub mod bcd;
+pub mod braille;
+pub mod char_info;
+pub mod checksum;
+pub mod cipher;
+pub mod data_url;
+pub mod ebcdic;
+pub mod figlet;
+pub mod gray;
+pub mod gsm;
+pub mod hamming;
+pub mod intel_hex;
+pub mod modbus;
+pub mod morse;
+pub mod nmea;
+pub mod parity;
+pub mod phonetic;
+pub mod punycode;
+pub mod sixbit;
+pub mod source_array;
+pub mod srecord;
+pub mod transliterate;
+pub mod xor;
+
 /// This function is passed decimal numbers and it then returns the hexadecimal representation. 
 ///
 /// Takes in a `Vec<u8>` where each value is a ascii values decimal number then will convert that to hexadecimal numbers which are returned as `Vec<String>`.
@@ -544,6 +624,30 @@ fn hex_to_dec(hex: &String) ->  Result<u8, String> {
 
 }
 
+fn hex_pairs_to_dec(hex: &str) -> Result<Vec<u8>, String> {
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("invalid digit found in string".to_string());
+    }
+
+    if hex.len() % 2 == 1 {
+        return Err("the hexadecimal string has an odd number of digits".to_string());
+    }
+
+    let mut dec = Vec::new();
+
+    for i in (0..hex.len()).step_by(2) {
+        dec.push(hex_to_dec(&hex[i..i + 2].to_string())?);
+    }
+
+    Ok(dec)
+
+}
+
+fn dec_to_hex_pairs(dec: &[u8]) -> String {
+    dec.iter().map(|d| format!("{:02X}", d)).collect()
+}
+
 #[cfg(test)]
 mod tests{
 
@@ -665,6 +769,31 @@ mod tests{
 
     }
 
+    mod hex_pairs_tests{
+
+        use super::super::*;
+
+        #[test]
+        fn hex_pairs_to_dec_test_happy_path(){
+
+            assert_eq!(hex_pairs_to_dec("00ff7F"), Ok(vec![0, 255, 127]));
+        }
+
+        #[test]
+        fn hex_pairs_to_dec_test_unhappy_path(){
+
+            assert_eq!(hex_pairs_to_dec("0F1"), Err("the hexadecimal string has an odd number of digits".to_string()));
+            assert_eq!(hex_pairs_to_dec("+F"), Err("invalid digit found in string".to_string()));
+        }
+
+        #[test]
+        fn dec_to_hex_pairs_test_happy_path(){
+
+            assert_eq!(dec_to_hex_pairs(&[0, 255, 10]), "00FF0A");
+        }
+
+    }
+
     mod decimal_to_hexadecimal_tests{
 
         use super::super::*;
@@ -0,0 +1,172 @@
+//! Building and parsing Modbus ASCII frames.
+//!
+//! A Modbus ASCII frame is a `:` followed by the address, function code, data and LRC, each byte
+//! written as two hexadecimal digits, and ended with a carriage return and line feed.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::modbus::*;
+//!
+//! let frame = build_frame(0x01, 0x03, &[0x00, 0x6B, 0x00, 0x03]);
+//!
+//! assert_eq!(frame, ":0103006B00038E\r\n");
+//! assert_eq!(parse_frame(&frame).unwrap().data, vec![0x00, 0x6B, 0x00, 0x03]);
+//! ```
+
+use std::fmt;
+
+use crate::checksum::lrc;
+use crate::{dec_to_hex_pairs, hex_pairs_to_dec};
+
+/// The fields of a Modbus ASCII frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModbusFrame {
+    /// The address of the device, `0` is the broadcast address.
+    pub address: u8,
+    /// The function code.
+    pub function: u8,
+    /// The data bytes following the function code.
+    pub data: Vec<u8>,
+}
+
+/// The errors that can be returned when parsing a Modbus ASCII frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModbusError {
+    /// The frame doesn't start with `:`.
+    MissingStart,
+    /// The frame doesn't end with a carriage return and line feed.
+    MissingEnd,
+    /// The characters between the start and end aren't pairs of hexadecimal digits.
+    InvalidHex(String),
+    /// The frame is too short to hold an address, function code and LRC.
+    TooShort,
+    /// The LRC in the frame doesn't match the one calculated from its bytes.
+    LrcMismatch {
+        /// The LRC calculated from the frame's bytes.
+        expected: u8,
+        /// The LRC found in the frame.
+        found: u8,
+    },
+}
+
+impl fmt::Display for ModbusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ModbusError::MissingStart => write!(f, "the frame doesn't start with ':'"),
+            ModbusError::MissingEnd => write!(f, "the frame doesn't end with CR LF"),
+            ModbusError::InvalidHex(e) => write!(f, "the frame isn't valid hexadecimal: {}", e),
+            ModbusError::TooShort => write!(f, "the frame is too short"),
+            ModbusError::LrcMismatch { expected, found } => write!(f, "LRC is {:02X} but should be {:02X}", found, expected),
+        }
+    }
+}
+
+impl std::error::Error for ModbusError {}
+
+impl ModbusFrame {
+    /// This method returns the frame as Modbus ASCII text, see [`build_frame`].
+    pub fn to_ascii(&self) -> String {
+        build_frame(self.address, self.function, &self.data)
+    }
+}
+
+/// This function builds a Modbus ASCII frame from an address, function code and data bytes.
+///
+/// The LRC is calculated over the address, function code and data.
+///
+/// # Example
+/// ```
+/// use ascii_converter::modbus::*;
+///
+/// assert_eq!(build_frame(0x11, 0x06, &[0x00, 0x01, 0x00, 0x03]), ":110600010003E5\r\n");
+/// ```
+pub fn build_frame(address: u8, function: u8, data: &[u8]) -> String {
+    let mut bytes = vec![address, function];
+
+    bytes.extend_from_slice(data);
+    bytes.push(lrc(&bytes).value as u8);
+
+    format!(":{}\r\n", dec_to_hex_pairs(&bytes))
+}
+
+/// This function parses a Modbus ASCII frame and checks its LRC.
+///
+/// Upper and lower case hexadecimal digits are accepted.
+///
+/// # Example
+/// ```
+/// use ascii_converter::modbus::*;
+///
+/// let frame = parse_frame(":110600010003E5\r\n").unwrap();
+///
+/// assert_eq!(frame, ModbusFrame { address: 0x11, function: 0x06, data: vec![0x00, 0x01, 0x00, 0x03] });
+///
+/// assert_eq!(parse_frame(":110600010003E6\r\n"), Err(ModbusError::LrcMismatch { expected: 0xE5, found: 0xE6 }));
+/// ```
+pub fn parse_frame(frame: &str) -> Result<ModbusFrame, ModbusError> {
+    if !frame.starts_with(':') {
+        return Err(ModbusError::MissingStart);
+    }
+
+    if !frame.ends_with("\r\n") {
+        return Err(ModbusError::MissingEnd);
+    }
+
+    let bytes = hex_pairs_to_dec(&frame[1..frame.len() - 2]).map_err(ModbusError::InvalidHex)?;
+
+    if bytes.len() < 3 {
+        return Err(ModbusError::TooShort);
+    }
+
+    let (body, found) = bytes.split_at(bytes.len() - 1);
+    let expected = lrc(body).value as u8;
+
+    if found[0] != expected {
+        return Err(ModbusError::LrcMismatch { expected, found: found[0] });
+    }
+
+    Ok(ModbusFrame {
+        address: body[0],
+        function: body[1],
+        data: body[2..].to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod build_frame_tests {
+        use super::super::*;
+
+        #[test]
+        fn build_frame_test_happy_path() {
+            assert_eq!(build_frame(0x00, 0x08, &[]), ":0008F8\r\n");
+            assert_eq!(build_frame(0xF7, 0x10, &[0xFF; 4]), ":F710FFFFFFFFFD\r\n");
+        }
+
+        #[test]
+        fn build_frame_test_round_trip() {
+            let frame = ModbusFrame { address: 0x2A, function: 0x03, data: (0..=251).collect() };
+
+            assert_eq!(parse_frame(&frame.to_ascii()), Ok(frame));
+        }
+    }
+
+    mod parse_frame_tests {
+        use super::super::*;
+
+        #[test]
+        fn parse_frame_test_lower_case() {
+            assert_eq!(parse_frame(":0103006b00038e\r\n").unwrap().data, vec![0x00, 0x6B, 0x00, 0x03]);
+        }
+
+        #[test]
+        fn parse_frame_test_unhappy_path() {
+            assert_eq!(parse_frame("0103006B00038E\r\n"), Err(ModbusError::MissingStart));
+            assert_eq!(parse_frame(":0103006B00038E\n"), Err(ModbusError::MissingEnd));
+            assert_eq!(parse_frame(":0103006B00038\r\n"), Err(ModbusError::InvalidHex("the hexadecimal string has an odd number of digits".to_string())));
+            assert_eq!(parse_frame(":01XX\r\n"), Err(ModbusError::InvalidHex("invalid digit found in string".to_string())));
+            assert_eq!(parse_frame(":01FF\r\n"), Err(ModbusError::TooShort));
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! Conversion between text and International Morse code.
+//!
+//! Letters, digits, punctuation and the common prosigns are supported. Prosigns are written
+//! in text as their letters wrapped in angle brackets, e.g. `<SK>`.
+//!
+//! The characters used for dots, dashes and the gaps between letters and words can all be
+//! changed through [`MorseOptions`].
+//!
+//! # Example
+//! ```
+//! use ascii_converter::morse::*;
+//!
+//! let options = MorseOptions::default();
+//!
+//! let morse = string_to_morse("SOS", &options).unwrap();
+//!
+//! assert_eq!(morse, "... --- ...");
+//! assert_eq!(morse_to_string(&morse, &options).unwrap(), "SOS");
+//! ```
+
+use std::fmt;
+
+const LETTERS: [(char, &str); 36] = [
+    ('A', ".-"), ('B', "-..."), ('C', "-.-."), ('D', "-.."), ('E', "."), ('F', "..-."),
+    ('G', "--."), ('H', "...."), ('I', ".."), ('J', ".---"), ('K', "-.-"), ('L', ".-.."),
+    ('M', "--"), ('N', "-."), ('O', "---"), ('P', ".--."), ('Q', "--.-"), ('R', ".-."),
+    ('S', "..."), ('T', "-"), ('U', "..-"), ('V', "...-"), ('W', ".--"), ('X', "-..-"),
+    ('Y', "-.--"), ('Z', "--.."),
+    ('0', "-----"), ('1', ".----"), ('2', "..---"), ('3', "...--"), ('4', "....-"),
+    ('5', "....."), ('6', "-...."), ('7', "--..."), ('8', "---.."), ('9', "----."),
+];
+
+const PUNCTUATION: [(char, &str); 18] = [
+    ('.', ".-.-.-"), (',', "--..--"), ('?', "..--.."), ('\'', ".----."), ('!', "-.-.--"),
+    ('/', "-..-."), ('(', "-.--."), (')', "-.--.-"), ('&', ".-..."), (':', "---..."),
+    (';', "-.-.-."), ('=', "-...-"), ('+', ".-.-."), ('-', "-....-"), ('_', "..--.-"),
+    ('"', ".-..-."), ('$', "...-..-"), ('@', ".--.-."),
+];
+
+const PROSIGNS: [(&str, &str); 10] = [
+    ("AR", ".-.-."), ("AS", ".-..."), ("BK", "-...-.-"), ("BT", "-...-"), ("CT", "-.-.-"),
+    ("HH", "........"), ("KN", "-.--."), ("SK", "...-.-"), ("SN", "...-."), ("SOS", "...---..."),
+];
+
+/// The errors that can be returned when converting to or from Morse code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MorseError {
+    /// The character has no Morse code equivalent.
+    UnsupportedCharacter(char),
+    /// The prosign written between `<` and `>` isn't a known prosign.
+    UnknownProsign(String),
+    /// The prosign is missing its closing `>`.
+    UnterminatedProsign(String),
+    /// The Morse sequence doesn't match any character or prosign.
+    InvalidCode(String),
+    /// The options share characters, so Morse code written with them can't be read back.
+    ConflictingOptions,
+}
+
+impl fmt::Display for MorseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MorseError::UnsupportedCharacter(c) => write!(f, "the character {:?} has no morse code equivalent", c),
+            MorseError::UnknownProsign(p) => write!(f, "<{}> isn't a known prosign", p),
+            MorseError::UnterminatedProsign(p) => write!(f, "the prosign <{} is missing its closing >", p),
+            MorseError::InvalidCode(code) => write!(f, "{:?} isn't a valid morse code sequence", code),
+            MorseError::ConflictingOptions => write!(f, "the dot, dash, separator and word gap must all be different"),
+        }
+    }
+}
+
+impl std::error::Error for MorseError {}
+
+/// The characters used when writing and reading Morse code.
+///
+/// The default uses `.` for dots, `-` for dashes, a single space between letters and
+/// `" / "` between words.
+///
+/// The dot, dash and separator must be different characters, and the word gap can't be empty,
+/// be just the separator or contain a dot or dash. Otherwise the conversions return
+/// `MorseError::ConflictingOptions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MorseOptions {
+    /// The character used for a dot (dit).
+    pub dot: char,
+    /// The character used for a dash (dah).
+    pub dash: char,
+    /// The character placed between the letters of a word.
+    pub separator: char,
+    /// The text placed between words.
+    pub word_gap: String,
+}
+
+impl Default for MorseOptions {
+    fn default() -> Self {
+        MorseOptions {
+            dot: '.',
+            dash: '-',
+            separator: ' ',
+            word_gap: " / ".to_string(),
+        }
+    }
+}
+
+impl MorseOptions {
+    fn check(&self) -> Result<(), MorseError> {
+        let symbols_clash = self.dot == self.dash || self.separator == self.dot || self.separator == self.dash;
+        let gap_clashes = self.word_gap.is_empty()
+            || self.word_gap == self.separator.to_string()
+            || self.word_gap.contains(self.dot)
+            || self.word_gap.contains(self.dash);
+
+        if symbols_clash || gap_clashes {
+            return Err(MorseError::ConflictingOptions);
+        }
+
+        Ok(())
+    }
+}
+
+/// This function returns the Morse code for the text passed in.
+///
+/// Letters are case insensitive and any run of whitespace is treated as a single gap between words.
+/// Prosigns can be included by wrapping them in angle brackets, e.g. `<AR>`.
+///
+/// If a character has no Morse equivalent a `MorseError::UnsupportedCharacter` is returned,
+/// and a prosign without its closing `>` returns a `MorseError::UnterminatedProsign`.
+///
+/// # Example
+/// ```
+/// use ascii_converter::morse::*;
+///
+/// let options = MorseOptions { dot: '*', ..MorseOptions::default() };
+///
+/// assert_eq!(string_to_morse("Hi all", &options).unwrap(), "**** ** / *- *-** *-**");
+/// assert_eq!(string_to_morse("73 <SK>", &MorseOptions::default()).unwrap(), "--... ...-- / ...-.-");
+/// ```
+pub fn string_to_morse(txt: &str, options: &MorseOptions) -> Result<String, MorseError> {
+    options.check()?;
+
+    let mut words = Vec::new();
+
+    for word in txt.split_whitespace() {
+        let mut codes = Vec::new();
+        let mut chars = word.chars();
+
+        while let Some(c) = chars.next() {
+            if c == '<' {
+                let mut prosign = String::new();
+                let mut terminated = false;
+
+                for c in chars.by_ref() {
+                    if c == '>' {
+                        terminated = true;
+                        break;
+                    }
+
+                    prosign.push(c);
+                }
+
+                if !terminated {
+                    return Err(MorseError::UnterminatedProsign(prosign));
+                }
+
+                match prosign_code(&prosign) {
+                    Some(code) => codes.push(code),
+                    None => return Err(MorseError::UnknownProsign(prosign)),
+                }
+            } else {
+                match char_code(c) {
+                    Some(code) => codes.push(code),
+                    None => return Err(MorseError::UnsupportedCharacter(c)),
+                }
+            }
+        }
+
+        let codes: Vec<String> = codes.iter().map(|code| render(code, options)).collect();
+        words.push(codes.join(&options.separator.to_string()));
+    }
+
+    Ok(words.join(&options.word_gap))
+}
+
+/// This function returns the text represented by the Morse code passed in.
+///
+/// The input should use the dot, dash, separator and word gap set in the options.
+/// Letters are returned in upper case, and prosigns without a punctuation equivalent are
+/// returned wrapped in angle brackets, e.g. `<SK>`.
+///
+/// If a sequence doesn't match any character a `MorseError::InvalidCode` is returned.
+///
+/// # Example
+/// ```
+/// use ascii_converter::morse::*;
+///
+/// let options = MorseOptions::default();
+///
+/// assert_eq!(morse_to_string(".... . .-.. .-.. --- / .-- --- .-. .-.. -..", &options).unwrap(), "HELLO WORLD");
+/// assert_eq!(morse_to_string("...-.-", &options).unwrap(), "<SK>");
+/// ```
+pub fn morse_to_string(morse: &str, options: &MorseOptions) -> Result<String, MorseError> {
+    options.check()?;
+
+    let mut words = Vec::new();
+
+    for word in morse.trim().split(options.word_gap.as_str()) {
+        let mut text = String::new();
+
+        for symbol in word.split(options.separator).filter(|s| !s.is_empty()) {
+            let mut code = String::new();
+
+            for c in symbol.chars() {
+                if c == options.dot {
+                    code.push('.');
+                } else if c == options.dash {
+                    code.push('-');
+                } else {
+                    return Err(MorseError::InvalidCode(symbol.to_string()));
+                }
+            }
+
+            match code_text(&code) {
+                Some(t) => text.push_str(&t),
+                None => return Err(MorseError::InvalidCode(symbol.to_string())),
+            }
+        }
+
+        if !text.is_empty() {
+            words.push(text);
+        }
+    }
+
+    Ok(words.join(" "))
+}
+
+fn char_code(c: char) -> Option<&'static str> {
+    let c = c.to_ascii_uppercase();
+
+    LETTERS.iter()
+        .chain(PUNCTUATION.iter())
+        .find(|(ch, _)| *ch == c)
+        .map(|(_, code)| *code)
+}
+
+fn prosign_code(prosign: &str) -> Option<&'static str> {
+    let prosign = prosign.to_ascii_uppercase();
+
+    PROSIGNS.iter()
+        .find(|(name, _)| *name == prosign)
+        .map(|(_, code)| *code)
+}
+
+fn code_text(code: &str) -> Option<String> {
+    if let Some((c, _)) = LETTERS.iter().chain(PUNCTUATION.iter()).find(|(_, m)| *m == code) {
+        return Some(c.to_string());
+    }
+
+    PROSIGNS.iter()
+        .find(|(_, m)| *m == code)
+        .map(|(name, _)| format!("<{}>", name))
+}
+
+fn render(code: &str, options: &MorseOptions) -> String {
+    code.chars()
+        .map(|c| if c == '.' { options.dot } else { options.dash })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod string_to_morse_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_morse_test_happy_path() {
+            let expected = ".... . .-.. .-.. --- / .-- --- .-. .-.. -.. -.-.--";
+
+            assert_eq!(string_to_morse("Hello world!", &MorseOptions::default()), Ok(expected.to_string()));
+        }
+
+        #[test]
+        fn string_to_morse_test_custom_options() {
+            let options = MorseOptions { dot: '0', dash: '1', separator: '|', word_gap: "||".to_string() };
+
+            assert_eq!(string_to_morse("ab c", &options), Ok("01|1000||1010".to_string()));
+        }
+
+        #[test]
+        fn string_to_morse_test_unhappy_path() {
+            assert_eq!(string_to_morse("a#b", &MorseOptions::default()), Err(MorseError::UnsupportedCharacter('#')));
+        }
+
+        #[test]
+        fn string_to_morse_test_unknown_prosign() {
+            assert_eq!(string_to_morse("<ZZ>", &MorseOptions::default()), Err(MorseError::UnknownProsign("ZZ".to_string())));
+        }
+
+        #[test]
+        fn string_to_morse_test_unterminated_prosign() {
+            assert_eq!(string_to_morse("73 <SK", &MorseOptions::default()), Err(MorseError::UnterminatedProsign("SK".to_string())));
+            assert_eq!(string_to_morse("<SK <AR>", &MorseOptions::default()), Err(MorseError::UnterminatedProsign("SK".to_string())));
+        }
+    }
+
+    mod morse_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn morse_to_string_test_happy_path() {
+            let input = "-.-. --.- / -.. . / .-- .---- .- .-- / ...-.-";
+
+            assert_eq!(morse_to_string(input, &MorseOptions::default()), Ok("CQ DE W1AW <SK>".to_string()));
+        }
+
+        #[test]
+        fn morse_to_string_test_round_trip() {
+            let options = MorseOptions::default();
+            let input = "THE QUICK BROWN FOX: 1234567890 (.,?'!/&;=+-_\"$@)";
+
+            let morse = string_to_morse(input, &options).unwrap();
+
+            assert_eq!(morse_to_string(&morse, &options), Ok(input.to_string()));
+        }
+
+        #[test]
+        fn morse_to_string_test_unhappy_path() {
+            assert_eq!(morse_to_string("...... .-", &MorseOptions::default()), Err(MorseError::InvalidCode("......".to_string())));
+        }
+
+        #[test]
+        fn morse_to_string_test_conflicting_options() {
+            let same_dot_and_dash = MorseOptions { dash: '.', ..MorseOptions::default() };
+            let separator_is_dot = MorseOptions { separator: '.', ..MorseOptions::default() };
+            let gap_is_separator = MorseOptions { word_gap: " ".to_string(), ..MorseOptions::default() };
+            let gap_has_dash = MorseOptions { word_gap: " - ".to_string(), ..MorseOptions::default() };
+
+            for options in [same_dot_and_dash, separator_is_dot, gap_is_separator, gap_has_dash].iter() {
+                assert_eq!(morse_to_string("...", options), Err(MorseError::ConflictingOptions));
+                assert_eq!(string_to_morse("SOS", options), Err(MorseError::ConflictingOptions));
+            }
+        }
+
+        #[test]
+        fn morse_to_string_test_invalid_character() {
+            assert_eq!(morse_to_string(".-x", &MorseOptions::default()), Err(MorseError::InvalidCode(".-x".to_string())));
+        }
+    }
+}
@@ -0,0 +1,306 @@
+//! Checksums, parsing and building of NMEA 0183 sentences.
+//!
+//! An NMEA sentence such as `$GPGGA,123519,4807.038,N,...*47` starts with `$` (or `!` for
+//! encapsulated sentences like AIS), followed by an address made of a two letter talker and a
+//! sentence type, comma separated fields and an optional checksum. The checksum is the XOR of
+//! every ascii byte between the start character and the `*`, written as two hexadecimal digits.
+//!
+//! Proprietary sentences, whose address starts with `P`, have a talker of `"P"` and the rest of
+//! the address as the sentence type.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::nmea::*;
+//!
+//! let sentence = "$GPGLL,4916.45,N,12311.12,W,225444,A*31";
+//!
+//! let parsed = parse_sentence(sentence).unwrap();
+//!
+//! assert_eq!(parsed.talker, "GP");
+//! assert_eq!(parsed.sentence_type, "GLL");
+//! assert_eq!(parsed.fields[0], "4916.45");
+//! assert_eq!(parsed.to_sentence().unwrap(), sentence);
+//! ```
+
+use crate::{dec_to_hex_pairs, hex_pairs_to_dec, string_to_decimals};
+
+/// The character a sentence starts with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SentenceStart {
+    /// `$`, used by most sentences.
+    Parametric,
+    /// `!`, used by encapsulated sentences such as AIS.
+    Encapsulated,
+}
+
+impl SentenceStart {
+    /// This method returns the start character, `$` or `!`.
+    pub fn to_char(self) -> char {
+        match self {
+            SentenceStart::Parametric => '$',
+            SentenceStart::Encapsulated => '!',
+        }
+    }
+}
+
+/// The parts of an NMEA sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NmeaSentence {
+    /// The character the sentence starts with.
+    pub start: SentenceStart,
+    /// The talker, such as `GP` for GPS or `P` for a proprietary sentence.
+    pub talker: String,
+    /// The sentence type, such as `GGA`.
+    pub sentence_type: String,
+    /// The fields after the address, empty fields are kept as empty strings.
+    pub fields: Vec<String>,
+}
+
+impl NmeaSentence {
+    /// This method builds the sentence back into text, see [`build_sentence`].
+    pub fn to_sentence(&self) -> Result<String, String> {
+        let fields: Vec<&str> = self.fields.iter().map(|f| f.as_str()).collect();
+
+        build_sentence(self.start, &self.talker, &self.sentence_type, &fields)
+    }
+}
+
+/// This function returns the NMEA checksum of a sentence.
+///
+/// A leading `$` or `!` and anything from the `*` onwards are ignored, so either a whole
+/// sentence or just the text between them can be passed in.
+///
+/// If the sentence contains a character that isn't ascii an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::nmea::*;
+///
+/// let sentence = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+///
+/// assert_eq!(nmea_checksum(sentence).unwrap(), 0x47);
+/// assert_eq!(nmea_checksum("GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,").unwrap(), 0x47);
+/// ```
+pub fn nmea_checksum(sentence: &str) -> Result<u8, String> {
+    let body = sentence.trim_start_matches(&['$', '!'][..]);
+    let body = body.split('*').next().unwrap_or("");
+
+    Ok(string_to_decimals(body)?.iter().fold(0, |sum, d| sum ^ d))
+}
+
+/// This function checks the checksum at the end of a sentence.
+///
+/// A trailing carriage return and line feed are ignored. If the sentence has no checksum, or the
+/// checksum isn't two hexadecimal digits, an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::nmea::*;
+///
+/// assert_eq!(verify_checksum("$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n"), Ok(true));
+/// assert_eq!(verify_checksum("$GPGLL,4916.45,N,12311.12,W,225444,A*30\r\n"), Ok(false));
+/// ```
+pub fn verify_checksum(sentence: &str) -> Result<bool, String> {
+    let sentence = sentence.trim_end_matches(&['\r', '\n'][..]);
+
+    match split_checksum(sentence)? {
+        (body, Some(checksum)) => Ok(nmea_checksum(body)? == checksum),
+        (_, None) => Err("the sentence has no checksum".to_string()),
+    }
+}
+
+/// This function splits a sentence into its talker, sentence type and fields.
+///
+/// If the sentence has a checksum it is checked, sentences without one are accepted. A trailing
+/// carriage return and line feed are ignored.
+///
+/// An error will be thrown if the sentence doesn't start with `$` or `!`, the address isn't
+/// valid or the checksum doesn't match.
+///
+/// # Example
+/// ```
+/// use ascii_converter::nmea::*;
+///
+/// let parsed = parse_sentence("$PGRME,15.0,M,45.0,M,25.0,M*1C").unwrap();
+///
+/// assert_eq!(parsed.talker, "P");
+/// assert_eq!(parsed.sentence_type, "GRME");
+///
+/// assert_eq!(parse_sentence("$GPGLL,4916.45,N*00"), Err("checksum is 00 but should be 3B".to_string()));
+/// ```
+pub fn parse_sentence(sentence: &str) -> Result<NmeaSentence, String> {
+    let sentence = sentence.trim_end_matches(&['\r', '\n'][..]);
+
+    let start = match sentence.chars().next() {
+        Some('$') => SentenceStart::Parametric,
+        Some('!') => SentenceStart::Encapsulated,
+        _ => return Err("the sentence doesn't start with '$' or '!'".to_string()),
+    };
+
+    let (body, checksum) = split_checksum(sentence)?;
+
+    if let Some(checksum) = checksum {
+        let expected = nmea_checksum(body)?;
+
+        if checksum != expected {
+            return Err(format!("checksum is {:02X} but should be {:02X}", checksum, expected));
+        }
+    }
+
+    let mut parts = body[1..].split(',');
+    let address = parts.next().unwrap_or("");
+    let (talker, sentence_type) = split_address(address)?;
+
+    Ok(NmeaSentence {
+        start,
+        talker: talker.to_string(),
+        sentence_type: sentence_type.to_string(),
+        fields: parts.map(|f| f.to_string()).collect(),
+    })
+}
+
+/// This function builds a sentence from its start character, talker, sentence type and fields,
+/// adding the checksum.
+///
+/// The sentence has no line ending, add `"\r\n"` when sending it.
+///
+/// An error will be thrown if the address isn't valid or a field contains a character that isn't
+/// ascii or is reserved by NMEA (`$`, `!`, `*`, `,`, carriage return or line feed).
+///
+/// # Example
+/// ```
+/// use ascii_converter::nmea::*;
+///
+/// assert_eq!(build_sentence(SentenceStart::Parametric, "GP", "GLL", &["4916.45", "N"]).unwrap(), "$GPGLL,4916.45,N*3B");
+/// assert_eq!(build_sentence(SentenceStart::Encapsulated, "AI", "VDM", &["1", "1"]).unwrap(), "!AIVDM,1,1*57");
+/// assert_eq!(build_sentence(SentenceStart::Parametric, "GP", "GLL", &["4916.45*"]), Err("the field \"4916.45*\" contains a reserved character".to_string()));
+/// ```
+pub fn build_sentence(start: SentenceStart, talker: &str, sentence_type: &str, fields: &[&str]) -> Result<String, String> {
+    let address = format!("{}{}", talker, sentence_type);
+
+    if split_address(&address)? != (talker, sentence_type) {
+        return Err(format!("{:?} isn't a valid talker", talker));
+    }
+
+    let mut body = address;
+
+    for field in fields.iter() {
+        string_to_decimals(field)?;
+
+        if field.contains(|c| "$!*,\r\n".contains(c)) {
+            return Err(format!("the field {:?} contains a reserved character", field));
+        }
+
+        body.push(',');
+        body.push_str(field);
+    }
+
+    Ok(format!("{}{}*{}", start.to_char(), body, dec_to_hex_pairs(&[nmea_checksum(&body)?])))
+}
+
+// Splits a sentence into the text before the `*` and the checksum after it, if there is one.
+fn split_checksum(sentence: &str) -> Result<(&str, Option<u8>), String> {
+    match sentence.find('*') {
+        Some(i) => {
+            let checksum = &sentence[i + 1..];
+
+            if checksum.len() != 2 {
+                return Err("the checksum must be two hexadecimal digits".to_string());
+            }
+
+            Ok((&sentence[..i], Some(hex_pairs_to_dec(checksum)?[0])))
+        }
+        None => Ok((sentence, None)),
+    }
+}
+
+fn split_address(address: &str) -> Result<(&str, &str), String> {
+    if address.len() < 3 || !address.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) {
+        return Err(format!("{:?} isn't a valid address", address));
+    }
+
+    if address.starts_with('P') {
+        Ok(address.split_at(1))
+    } else {
+        Ok(address.split_at(2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod nmea_checksum_tests {
+        use super::super::*;
+
+        #[test]
+        fn nmea_checksum_test_happy_path() {
+            assert_eq!(nmea_checksum("$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A"), Ok(0x6A));
+            assert_eq!(nmea_checksum("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C"), Ok(0x5C));
+            assert_eq!(nmea_checksum(""), Ok(0));
+        }
+
+        #[test]
+        fn nmea_checksum_test_unhappy_path() {
+            assert_eq!(nmea_checksum("$GPTXT,°"), Err("A character in the string isn't apart of the ascii table".to_string()));
+            assert_eq!(verify_checksum("$GPGLL,4916.45,N"), Err("the sentence has no checksum".to_string()));
+            assert_eq!(verify_checksum("$GPGLL,4916.45,N*7"), Err("the checksum must be two hexadecimal digits".to_string()));
+        }
+    }
+
+    mod parse_sentence_tests {
+        use super::super::*;
+
+        #[test]
+        fn parse_sentence_test_happy_path() {
+            let parsed = parse_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n").unwrap();
+
+            assert_eq!(parsed.start, SentenceStart::Parametric);
+            assert_eq!(parsed.talker, "GP");
+            assert_eq!(parsed.sentence_type, "GGA");
+            assert_eq!(parsed.fields.len(), 14);
+            assert_eq!(parsed.fields[13], "");
+        }
+
+        #[test]
+        fn parse_sentence_test_without_checksum() {
+            let parsed = parse_sentence("$GNZDA,201530.00,04,07,2002,00,00").unwrap();
+
+            assert_eq!(parsed.talker, "GN");
+            assert_eq!(parsed.fields, vec!["201530.00", "04", "07", "2002", "00", "00"]);
+        }
+
+        #[test]
+        fn parse_sentence_test_unhappy_path() {
+            assert_eq!(parse_sentence("GPGLL,4916.45,N*3B"), Err("the sentence doesn't start with '$' or '!'".to_string()));
+            assert_eq!(parse_sentence("$GP,4916.45,N"), Err("\"GP\" isn't a valid address".to_string()));
+            assert_eq!(parse_sentence("$GPGLL,4916.45,N*0G"), Err("invalid digit found in string".to_string()));
+        }
+    }
+
+    mod build_sentence_tests {
+        use super::super::*;
+
+        #[test]
+        fn build_sentence_test_round_trip() {
+            let sentence = "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A";
+
+            assert_eq!(parse_sentence(sentence).unwrap().to_sentence(), Ok(sentence.to_string()));
+        }
+
+        #[test]
+        fn build_sentence_test_ais_round_trip() {
+            let sentence = "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C";
+            let parsed = parse_sentence(sentence).unwrap();
+
+            assert_eq!(parsed.start, SentenceStart::Encapsulated);
+            assert_eq!(parsed.to_sentence(), Ok(sentence.to_string()));
+        }
+
+        #[test]
+        fn build_sentence_test_unhappy_path() {
+            assert_eq!(build_sentence(SentenceStart::Parametric, "gp", "GLL", &[]), Err("\"gpGLL\" isn't a valid address".to_string()));
+            assert_eq!(build_sentence(SentenceStart::Parametric, "GPG", "LL", &[]), Err("\"GPG\" isn't a valid talker".to_string()));
+            assert_eq!(build_sentence(SentenceStart::Parametric, "GP", "TXT", &["°C"]), Err("A character in the string isn't apart of the ascii table".to_string()));
+        }
+    }
+}
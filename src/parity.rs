@@ -0,0 +1,200 @@
+//! Parity bits for seven bit ascii.
+//!
+//! Serial links often send seven bit ascii with a parity bit in the eighth bit. The functions in
+//! this module add the chosen [`Parity`] to each character's binary form and check and remove it
+//! again, reporting the index of every character that fails the check.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::parity::*;
+//!
+//! let framed = string_to_binary_with_parity("hi", Parity::Even).unwrap();
+//!
+//! assert_eq!(framed, vec![11101000, 1101001]);
+//! assert_eq!(binary_with_parity_to_string(&framed, Parity::Even).unwrap(), "hi");
+//! ```
+
+use crate::{binary_to_decimal, dec_to_bit, decimals_to_string, string_to_decimals};
+
+/// The kinds of parity that can be placed in the eighth bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parity {
+    /// The parity bit makes the number of `1` bits even.
+    Even,
+    /// The parity bit makes the number of `1` bits odd.
+    Odd,
+    /// The parity bit is always `1`.
+    Mark,
+    /// The parity bit is always `0`.
+    Space,
+}
+
+impl Parity {
+    fn bit(self, value: u8) -> u8 {
+        let ones = value.count_ones() % 2;
+
+        match self {
+            Parity::Even => ones as u8,
+            Parity::Odd => 1 - ones as u8,
+            Parity::Mark => 1,
+            Parity::Space => 0,
+        }
+    }
+}
+
+/// This function returns the binary form of ascii decimals with a parity bit added.
+///
+/// The parity bit is placed in the eighth bit of each value.
+///
+/// If a value is above `127` an error will be thrown as the eighth bit is already in use.
+///
+/// # Example
+/// ```
+/// use ascii_converter::parity::*;
+///
+/// assert_eq!(decimals_to_binary_with_parity(&[65, 67], Parity::Odd).unwrap(), vec![11000001, 1000011]);
+/// assert_eq!(decimals_to_binary_with_parity(&[65, 67], Parity::Mark).unwrap(), vec![11000001, 11000011]);
+/// ```
+pub fn decimals_to_binary_with_parity(dec_vec: &[u8], parity: Parity) -> Result<Vec<u32>, String> {
+    let mut binary = Vec::new();
+
+    for d in dec_vec.iter() {
+        if *d > 127 {
+            return Err("this function doesn't support values over 127".to_string());
+        }
+
+        binary.push(dec_to_bit(d | (parity.bit(*d) << 7)));
+    }
+
+    Ok(binary)
+}
+
+/// This function returns the binary form of a string with a parity bit added to each character.
+///
+/// If the string contains a character that isn't ascii an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::parity::*;
+///
+/// assert_eq!(string_to_binary_with_parity("ok", Parity::Space).unwrap(), vec![1101111, 1101011]);
+/// ```
+pub fn string_to_binary_with_parity(txt: &str, parity: Parity) -> Result<Vec<u32>, String> {
+    decimals_to_binary_with_parity(&string_to_decimals(txt)?, parity)
+}
+
+/// This function returns the indices of the binary numbers that fail the parity check.
+///
+/// An empty `Vec` means every number passed the check. If a number passed in isn't a binary
+/// byte an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::parity::*;
+///
+/// assert_eq!(parity_failures(&[11101000, 1101001, 1101001], Parity::Even).unwrap(), vec![]);
+/// assert_eq!(parity_failures(&[11101000, 1101000, 1101001], Parity::Even).unwrap(), vec![1]);
+/// ```
+pub fn parity_failures(bin_vec: &[u32], parity: Parity) -> Result<Vec<usize>, String> {
+    let dec = binary_to_decimal(&bin_vec.to_vec())?;
+
+    Ok(dec.iter()
+        .enumerate()
+        .filter(|(_, d)| *d >> 7 != parity.bit(*d & 0x7F))
+        .map(|(i, _)| i)
+        .collect())
+}
+
+/// This function checks and removes the parity bit from binary numbers, returning ascii decimals.
+///
+/// If any number fails the parity check an error listing the indices of the failing numbers is
+/// thrown, use [`parity_failures`] to get the indices as a `Vec`.
+///
+/// # Example
+/// ```
+/// use ascii_converter::parity::*;
+///
+/// assert_eq!(strip_parity(&[11000001, 1000011], Parity::Odd).unwrap(), vec![65, 67]);
+/// assert_eq!(strip_parity(&[1000001, 11000011], Parity::Odd), Err("parity check failed at indices [0, 1]".to_string()));
+/// ```
+pub fn strip_parity(bin_vec: &[u32], parity: Parity) -> Result<Vec<u8>, String> {
+    let failures = parity_failures(bin_vec, parity)?;
+
+    if !failures.is_empty() {
+        return Err(format!("parity check failed at indices {:?}", failures));
+    }
+
+    Ok(binary_to_decimal(&bin_vec.to_vec())?.iter().map(|d| d & 0x7F).collect())
+}
+
+/// This function checks and removes the parity bit from binary numbers and returns the string.
+///
+/// An error will be thrown if any number fails the parity check or is outside the ascii range `32 - 126`
+/// once the parity bit is removed.
+///
+/// # Example
+/// ```
+/// use ascii_converter::parity::*;
+///
+/// assert_eq!(binary_with_parity_to_string(&[11101000, 1101001], Parity::Even).unwrap(), "hi");
+/// ```
+pub fn binary_with_parity_to_string(bin_vec: &[u32], parity: Parity) -> Result<String, String> {
+    decimals_to_string(&strip_parity(bin_vec, parity)?)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod decimals_to_binary_with_parity_tests {
+        use super::super::*;
+
+        #[test]
+        fn decimals_to_binary_with_parity_test_happy_path() {
+            let input = vec![0, 1, 3, 127];
+
+            assert_eq!(decimals_to_binary_with_parity(&input, Parity::Even), Ok(vec![0, 10000001, 11, 11111111]));
+            assert_eq!(decimals_to_binary_with_parity(&input, Parity::Odd), Ok(vec![10000000, 1, 10000011, 1111111]));
+        }
+
+        #[test]
+        fn decimals_to_binary_with_parity_test_unhappy_path() {
+            assert_eq!(decimals_to_binary_with_parity(&[128], Parity::Even), Err("this function doesn't support values over 127".to_string()));
+        }
+
+    }
+
+    mod string_to_binary_with_parity_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_binary_with_parity_test_unhappy_path() {
+            assert_eq!(string_to_binary_with_parity("☢️", Parity::Even), Err("A character in the string isn't apart of the ascii table".to_string()));
+        }
+    }
+
+    mod strip_parity_tests {
+        use super::super::*;
+
+        #[test]
+        fn strip_parity_test_round_trip() {
+            let input: Vec<u8> = (0..=127).collect();
+
+            for parity in [Parity::Even, Parity::Odd, Parity::Mark, Parity::Space].iter() {
+                let framed = decimals_to_binary_with_parity(&input, *parity).unwrap();
+
+                assert_eq!(strip_parity(&framed, *parity), Ok(input.clone()));
+            }
+        }
+
+        #[test]
+        fn strip_parity_test_mark_and_space() {
+            assert_eq!(parity_failures(&[11000001, 1000001], Parity::Mark), Ok(vec![1]));
+            assert_eq!(parity_failures(&[11000001, 1000001], Parity::Space), Ok(vec![0]));
+        }
+
+        #[test]
+        fn strip_parity_test_unhappy_path() {
+            assert_eq!(strip_parity(&[1000001, 2], Parity::Even), Err("number passed in isn't binary".to_string()));
+        }
+    }
+}
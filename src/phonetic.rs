@@ -0,0 +1,143 @@
+//! Spelling ascii text with the ICAO (NATO) phonetic alphabet.
+//!
+//! Letters use the ICAO code words, digits use their English names and every other printable
+//! character has a single word name such as `Dash` or `Left-Bracket`, so any printable ascii
+//! text can be read out and typed back in.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::phonetic::*;
+//!
+//! let spoken = string_to_phonetic("4F").unwrap();
+//!
+//! assert_eq!(spoken, "Four Foxtrot");
+//! assert_eq!(phonetic_to_string(&spoken).unwrap(), "4F");
+//! ```
+
+const WORDS: [(char, &str); 69] = [
+    ('A', "Alfa"), ('B', "Bravo"), ('C', "Charlie"), ('D', "Delta"), ('E', "Echo"),
+    ('F', "Foxtrot"), ('G', "Golf"), ('H', "Hotel"), ('I', "India"), ('J', "Juliett"),
+    ('K', "Kilo"), ('L', "Lima"), ('M', "Mike"), ('N', "November"), ('O', "Oscar"),
+    ('P', "Papa"), ('Q', "Quebec"), ('R', "Romeo"), ('S', "Sierra"), ('T', "Tango"),
+    ('U', "Uniform"), ('V', "Victor"), ('W', "Whiskey"), ('X', "X-ray"), ('Y', "Yankee"),
+    ('Z', "Zulu"),
+    ('0', "Zero"), ('1', "One"), ('2', "Two"), ('3', "Three"), ('4', "Four"),
+    ('5', "Five"), ('6', "Six"), ('7', "Seven"), ('8', "Eight"), ('9', "Nine"),
+    (' ', "Space"), ('!', "Exclamation"), ('"', "Quote"), ('#', "Hash"), ('$', "Dollar"),
+    ('%', "Percent"), ('&', "Ampersand"), ('\'', "Apostrophe"), ('(', "Left-Parenthesis"),
+    (')', "Right-Parenthesis"), ('*', "Asterisk"), ('+', "Plus"), (',', "Comma"),
+    ('-', "Dash"), ('.', "Period"), ('/', "Slash"), (':', "Colon"), (';', "Semicolon"),
+    ('<', "Less-Than"), ('=', "Equals"), ('>', "Greater-Than"), ('?', "Question"),
+    ('@', "At"), ('[', "Left-Bracket"), ('\\', "Backslash"), (']', "Right-Bracket"),
+    ('^', "Caret"), ('_', "Underscore"), ('`', "Backtick"), ('{', "Left-Brace"),
+    ('|', "Pipe"), ('}', "Right-Brace"), ('~', "Tilde"),
+];
+
+// Common spellings and ICAO radiotelephony pronunciations that are accepted when parsing.
+const ALTERNATIVES: [(char, &str); 9] = [
+    ('A', "Alpha"), ('J', "Juliet"), ('X', "Xray"), ('3', "Tree"),
+    ('4', "Fower"), ('5', "Fife"), ('9', "Niner"), ('.', "Decimal"), ('.', "Stop"),
+];
+
+/// This function spells the text passed in using the ICAO phonetic alphabet.
+///
+/// Each character of the `&str` is replaced by its code word and the words are joined by spaces.
+/// Letters are case insensitive so `a` and `A` are both spelt `Alfa`.
+///
+/// Only printable ascii characters (`32 - 126`) are supported, any other character will cause an error.
+///
+/// # Example
+/// ```
+/// use ascii_converter::phonetic::*;
+///
+/// assert_eq!(string_to_phonetic("Hi 5!").unwrap(), "Hotel India Space Five Exclamation");
+/// ```
+pub fn string_to_phonetic(txt: &str) -> Result<String, String> {
+    let mut words = Vec::new();
+
+    for c in txt.chars() {
+        let upper = c.to_ascii_uppercase();
+
+        match WORDS.iter().find(|(ch, _)| *ch == upper) {
+            Some((_, word)) => words.push(*word),
+            None => return Err(format!("the character {:?} has no phonetic spelling", c)),
+        }
+    }
+
+    Ok(words.join(" "))
+}
+
+/// This function returns the text spelt out by a sequence of phonetic code words.
+///
+/// The words should be separated by whitespace and are matched case insensitively. Common
+/// alternatives such as `Alpha`, `Juliet` and the radiotelephony digits `Tree`, `Fower`,
+/// `Fife` and `Niner` are also accepted. Letters are returned in upper case.
+///
+/// If a word isn't recognised an error will be returned.
+///
+/// # Example
+/// ```
+/// use ascii_converter::phonetic::*;
+///
+/// assert_eq!(phonetic_to_string("alpha bravo niner Dash tree").unwrap(), "AB9-3");
+/// ```
+pub fn phonetic_to_string(spoken: &str) -> Result<String, String> {
+    let mut text = String::new();
+
+    for word in spoken.split_whitespace() {
+        match WORDS.iter().chain(ALTERNATIVES.iter()).find(|(_, w)| w.eq_ignore_ascii_case(word)) {
+            Some((c, _)) => text.push(*c),
+            None => return Err(format!("{:?} isn't a phonetic alphabet word", word)),
+        }
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod string_to_phonetic_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_phonetic_test_happy_path() {
+            let expected = "Delta Echo Alfa Delta Dash Bravo Echo Echo Foxtrot Space Zero X-ray Seven Foxtrot";
+
+            assert_eq!(string_to_phonetic("dead-beef 0x7F"), Ok(expected.to_string()));
+        }
+
+        #[test]
+        fn string_to_phonetic_test_unhappy_path() {
+            assert_eq!(string_to_phonetic("a\tb"), Err("the character '\\t' has no phonetic spelling".to_string()));
+        }
+
+        #[test]
+        fn string_to_phonetic_test_every_printable_character() {
+            let input: String = (32..=126u8).map(|d| d as char).collect();
+
+            let spoken = string_to_phonetic(&input).unwrap();
+
+            assert_eq!(phonetic_to_string(&spoken), Ok(input.to_ascii_uppercase()));
+        }
+    }
+
+    mod phonetic_to_string_tests {
+        use super::super::*;
+
+        #[test]
+        fn phonetic_to_string_test_happy_path() {
+            assert_eq!(phonetic_to_string("HOTEL echo Lima lima Oscar"), Ok("HELLO".to_string()));
+        }
+
+        #[test]
+        fn phonetic_to_string_test_alternatives() {
+            assert_eq!(phonetic_to_string("Alpha Xray X-ray Juliet Tree Niner Decimal"), Ok("AXXJ39.".to_string()));
+        }
+
+        #[test]
+        fn phonetic_to_string_test_unhappy_path() {
+            assert_eq!(phonetic_to_string("Hotel Elephant"), Err("\"Elephant\" isn't a phonetic alphabet word".to_string()));
+        }
+    }
+}
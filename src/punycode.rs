@@ -0,0 +1,366 @@
+//! RFC 3492 Punycode and the ascii `xn--` labels of internationalised domain names.
+//!
+//! Punycode turns a Unicode string into ascii letters, digits and hyphens by writing the ascii
+//! characters first, then a `-`, then the positions and code points of the other characters as
+//! base 36 numbers. Domain names use it for each label that isn't already ascii, adding an
+//! `xn--` prefix to mark the label as encoded.
+//!
+//! The domain functions only lowercase the labels, they don't apply the rest of the IDNA
+//! mapping such as Unicode normalisation.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::punycode::*;
+//!
+//! assert_eq!(encode("bücher").unwrap(), "bcher-kva");
+//! assert_eq!(decode("bcher-kva").unwrap(), "bücher");
+//!
+//! assert_eq!(domain_to_ascii("Bücher.example").unwrap(), "xn--bcher-kva.example");
+//! assert_eq!(domain_to_unicode("xn--bcher-kva.example").unwrap(), "bücher.example");
+//! ```
+
+use std::fmt;
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+const ACE_PREFIX: &str = "xn--";
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// The full stop and the ideographic and fullwidth full stops that also separate labels.
+const DOTS: [char; 4] = ['.', '。', '．', '｡'];
+
+/// The errors that can be returned when converting to or from Punycode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PunycodeError {
+    /// The string is too long or its code points too spread out for the 32 bit arithmetic used by Punycode.
+    Overflow,
+    /// The character isn't allowed at its place in the Punycode string.
+    InvalidCharacter(char),
+    /// The Punycode string ends part way through a number.
+    UnexpectedEnd,
+    /// Decoding gave a value that isn't a Unicode scalar value.
+    InvalidCodePoint(u32),
+    /// The ascii form of the label is longer than the 63 characters DNS allows.
+    LabelTooLong(String),
+}
+
+impl fmt::Display for PunycodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PunycodeError::Overflow => write!(f, "the punycode value overflowed"),
+            PunycodeError::InvalidCharacter(c) => write!(f, "{:?} isn't valid in punycode here", c),
+            PunycodeError::UnexpectedEnd => write!(f, "the punycode string ends part way through a number"),
+            PunycodeError::InvalidCodePoint(n) => write!(f, "U+{:04X} isn't a valid unicode code point", n),
+            PunycodeError::LabelTooLong(label) => write!(f, "the label {:?} is longer than {} characters", label, MAX_LABEL_LENGTH),
+        }
+    }
+}
+
+impl std::error::Error for PunycodeError {}
+
+/// This function encodes a Unicode string as Punycode.
+///
+/// The result doesn't have the `xn--` prefix, use [`domain_to_ascii`] for domain names.
+///
+/// # Example
+/// ```
+/// use ascii_converter::punycode::*;
+///
+/// assert_eq!(encode("München").unwrap(), "Mnchen-3ya");
+/// assert_eq!(encode("他们为什么不说中文").unwrap(), "ihqwcrb4cv8a8dqg056pqjye");
+/// assert_eq!(encode("ascii").unwrap(), "ascii-");
+/// ```
+pub fn encode(input: &str) -> Result<String, PunycodeError> {
+    let input: Vec<u32> = input.chars().map(|c| c as u32).collect();
+
+    let mut output: String = input.iter().filter(|c| **c < 0x80).map(|c| *c as u8 as char).collect();
+    let basic = output.len() as u32;
+    let mut handled = basic;
+
+    if basic > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    while (handled as usize) < input.len() {
+        let m = *input.iter().filter(|c| **c >= n).min().unwrap_or(&n);
+
+        delta = (m - n).checked_mul(handled + 1)
+            .and_then(|d| delta.checked_add(d))
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+
+        for c in input.iter() {
+            if *c < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            }
+
+            if *c == n {
+                let mut q = delta;
+                let mut k = BASE;
+
+                loop {
+                    let t = threshold(k, bias);
+
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(digit_to_char(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+
+                output.push(digit_to_char(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+        n += 1;
+    }
+
+    Ok(output)
+}
+
+/// This function decodes a Punycode string back into Unicode.
+///
+/// The input shouldn't have the `xn--` prefix, use [`domain_to_unicode`] for domain names.
+/// Digits are read case-insensitively.
+///
+/// # Example
+/// ```
+/// use ascii_converter::punycode::*;
+///
+/// assert_eq!(decode("3B-ww4c5e180e575a65lsy2b").unwrap(), "3年B組金八先生");
+/// assert_eq!(decode("bcher-k!a"), Err(PunycodeError::InvalidCharacter('!')));
+/// assert_eq!(decode("99999999999"), Err(PunycodeError::Overflow));
+/// ```
+pub fn decode(input: &str) -> Result<String, PunycodeError> {
+    let (basic, extended) = match input.rfind('-') {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+
+    if let Some(c) = basic.chars().find(|c| !c.is_ascii()) {
+        return Err(PunycodeError::InvalidCharacter(c));
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut chars = extended.chars().peekable();
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    while chars.peek().is_some() {
+        let old_i = i;
+        let mut w: u32 = 1;
+        let mut k = BASE;
+
+        loop {
+            let c = chars.next().ok_or(PunycodeError::UnexpectedEnd)?;
+            let digit = char_to_digit(c).ok_or(PunycodeError::InvalidCharacter(c))?;
+
+            i = digit.checked_mul(w).and_then(|d| i.checked_add(d)).ok_or(PunycodeError::Overflow)?;
+
+            let t = threshold(k, bias);
+
+            if digit < t {
+                break;
+            }
+
+            w = w.checked_mul(BASE - t).ok_or(PunycodeError::Overflow)?;
+            k += BASE;
+        }
+
+        let length = output.len() as u32 + 1;
+
+        bias = adapt(i - old_i, length, old_i == 0);
+        n = n.checked_add(i / length).ok_or(PunycodeError::Overflow)?;
+        i %= length;
+
+        output.insert(i as usize, std::char::from_u32(n).ok_or(PunycodeError::InvalidCodePoint(n))?);
+        i += 1;
+    }
+
+    Ok(output.into_iter().collect())
+}
+
+/// This function converts each label of a domain name to its ascii form.
+///
+/// Labels are lowercased, and labels that aren't ascii are Punycode encoded with an `xn--`
+/// prefix. The ideographic full stops `。`, `．` and `｡` are treated as dots.
+///
+/// An error will be thrown if a label can't be encoded or its ascii form is longer than 63 characters.
+///
+/// # Example
+/// ```
+/// use ascii_converter::punycode::*;
+///
+/// assert_eq!(domain_to_ascii("www.例え.テスト").unwrap(), "www.xn--r8jz45g.xn--zckzah");
+/// ```
+pub fn domain_to_ascii(domain: &str) -> Result<String, PunycodeError> {
+    let mut labels = Vec::new();
+
+    for label in domain.split(&DOTS[..]) {
+        let label = label.to_lowercase();
+
+        let ascii = if label.is_ascii() {
+            label
+        } else {
+            format!("{}{}", ACE_PREFIX, encode(&label)?)
+        };
+
+        if ascii.len() > MAX_LABEL_LENGTH {
+            return Err(PunycodeError::LabelTooLong(ascii));
+        }
+
+        labels.push(ascii);
+    }
+
+    Ok(labels.join("."))
+}
+
+/// This function converts each `xn--` label of a domain name back to Unicode.
+///
+/// Other labels are left as they are, apart from being lowercased. As in [`domain_to_ascii`],
+/// the ideographic full stops `。`, `．` and `｡` are treated as dots.
+///
+/// An error will be thrown if an `xn--` label isn't valid Punycode.
+///
+/// # Example
+/// ```
+/// use ascii_converter::punycode::*;
+///
+/// assert_eq!(domain_to_unicode("WWW.XN--R8JZ45G.XN--ZCKZAH").unwrap(), "www.例え.テスト");
+/// ```
+pub fn domain_to_unicode(domain: &str) -> Result<String, PunycodeError> {
+    let mut labels = Vec::new();
+
+    for label in domain.split(&DOTS[..]) {
+        let label = label.to_lowercase();
+
+        match label.strip_prefix(ACE_PREFIX) {
+            Some(encoded) => labels.push(decode(encoded)?),
+            None => labels.push(label),
+        }
+    }
+
+    Ok(labels.join("."))
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn adapt(delta: u32, points: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / DAMP } else { delta / 2 };
+    let mut k = 0;
+
+    delta += delta / points;
+
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+
+    k + ((BASE - T_MIN + 1) * delta) / (delta + SKEW)
+}
+
+fn digit_to_char(digit: u32) -> char {
+    match digit {
+        0..=25 => (b'a' + digit as u8) as char,
+        _ => (b'0' + (digit - 26) as u8) as char,
+    }
+}
+
+fn char_to_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    // Sample strings from section 7.1 of RFC 3492.
+    const SAMPLES: [(&str, &str); 6] = [
+        ("ليهمابتكلموشعربي؟", "egbpdaj6bu4bxfgehfvwxn"),
+        ("他們爲什麽不說中文", "ihqwctvzc91f659drss3x8bo0yb"),
+        ("Pročprostěnemluvíčesky", "Proprostnemluvesky-uyb24dma41a"),
+        ("почемужеонинеговорятпорусски", "b1abfaaepdrnnbgefbadotcwatmq2g4l"),
+        ("-> $1.00 <-", "-> $1.00 <--"),
+        ("MajiでKoiする5秒前", "MajiKoi5-783gue6qz075azm5e"),
+    ];
+
+    mod encode_tests {
+        use super::super::*;
+        use super::SAMPLES;
+
+        #[test]
+        fn encode_test_rfc_samples() {
+            for (unicode, punycode) in SAMPLES.iter() {
+                assert_eq!(encode(unicode).unwrap(), *punycode);
+            }
+        }
+
+        #[test]
+        fn encode_test_empty() {
+            assert_eq!(encode(""), Ok(String::new()));
+        }
+
+        #[test]
+        fn domain_to_ascii_test_unhappy_path() {
+            let long = "ü".repeat(64);
+
+            assert!(matches!(domain_to_ascii(&long), Err(PunycodeError::LabelTooLong(_))));
+        }
+    }
+
+    mod decode_tests {
+        use super::super::*;
+        use super::SAMPLES;
+
+        #[test]
+        fn decode_test_rfc_samples() {
+            for (unicode, punycode) in SAMPLES.iter() {
+                assert_eq!(decode(punycode).unwrap(), *unicode);
+            }
+        }
+
+        #[test]
+        fn decode_test_unhappy_path() {
+            assert_eq!(decode("ü-kva"), Err(PunycodeError::InvalidCharacter('ü')));
+            assert_eq!(decode("bcher-kv"), Err(PunycodeError::UnexpectedEnd));
+            assert_eq!(decode("bb0c"), Err(PunycodeError::InvalidCodePoint(0xDCC2)));
+        }
+
+        #[test]
+        fn domain_to_unicode_test_happy_path() {
+            assert_eq!(domain_to_unicode("xn--mnchen-3ya.de."), Ok("münchen.de.".to_string()));
+            assert_eq!(domain_to_unicode("example.com"), Ok("example.com".to_string()));
+            assert_eq!(domain_to_unicode("xn--r8jz45g。xn--zckzah｡jp"), Ok("例え.テスト.jp".to_string()));
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Six bit character codes and packing them into contiguous bitstreams.
+//!
+//! Six bit codes cover the 64 ascii characters `32 - 95`, which is every printable character
+//! apart from lower case letters and `` ` { | } ~ ``. Two layouts are supported through
+//! [`SixBitCharset`]:
+//!
+//! - `DecSixbit` - DEC SIXBIT as used on the PDP-10, where space is `0` and `_` is `63`.
+//!
+//! - `Ascii6` - the layout used by AIS and ICAO aviation messages, where `@` is `0`, `_` is `31`
+//!   and space is `32`. This is the low six bits of the ascii value.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::sixbit::*;
+//!
+//! let codes = string_to_sixbit("PDP10", SixBitCharset::DecSixbit).unwrap();
+//! let packed = pack_sixbit(&codes).unwrap();
+//!
+//! assert_eq!(packed.len(), 4);
+//! assert_eq!(sixbit_to_string(&unpack_sixbit(&packed, 5).unwrap(), SixBitCharset::DecSixbit).unwrap(), "PDP10");
+//! ```
+
+use crate::decimals_to_binary;
+
+/// The six bit character layouts that can be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SixBitCharset {
+    /// DEC SIXBIT, the ascii value minus `32`.
+    DecSixbit,
+    /// Six bit ascii used by AIS and ICAO, the low six bits of the ascii value.
+    Ascii6,
+}
+
+/// This function converts text into six bit codes.
+///
+/// Lower case letters are folded to upper case. Any other character outside the range `32 - 95`
+/// will cause an error to be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::sixbit::*;
+///
+/// assert_eq!(string_to_sixbit("Ab 1", SixBitCharset::DecSixbit).unwrap(), vec![33, 34, 0, 17]);
+/// assert_eq!(string_to_sixbit("Ab 1", SixBitCharset::Ascii6).unwrap(), vec![1, 2, 32, 49]);
+/// ```
+pub fn string_to_sixbit(txt: &str, charset: SixBitCharset) -> Result<Vec<u8>, String> {
+    let mut codes = Vec::new();
+
+    for c in txt.chars() {
+        let c = c.to_ascii_uppercase();
+
+        if !(' '..='_').contains(&c) {
+            return Err(format!("the character {:?} has no six bit code", c));
+        }
+
+        codes.push(match charset {
+            SixBitCharset::DecSixbit => c as u8 - 32,
+            SixBitCharset::Ascii6 => c as u8 & 0x3F,
+        });
+    }
+
+    Ok(codes)
+}
+
+/// This function converts six bit codes into text.
+///
+/// Each code should be in the range `0 - 63`, otherwise an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::sixbit::*;
+///
+/// assert_eq!(sixbit_to_string(&[40, 37, 44, 44, 47], SixBitCharset::DecSixbit).unwrap(), "HELLO");
+/// ```
+pub fn sixbit_to_string(codes: &[u8], charset: SixBitCharset) -> Result<String, String> {
+    let mut text = String::new();
+
+    for code in codes.iter() {
+        check_code(*code)?;
+
+        text.push(match charset {
+            SixBitCharset::DecSixbit => code + 32,
+            SixBitCharset::Ascii6 if *code < 32 => code + 64,
+            SixBitCharset::Ascii6 => *code,
+        } as char);
+    }
+
+    Ok(text)
+}
+
+/// This function returns six bit codes in the crate's binary form.
+///
+/// Each code should be in the range `0 - 63`, otherwise an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::sixbit::*;
+///
+/// assert_eq!(sixbit_to_binary(&[40, 63, 0]).unwrap(), vec![101000, 111111, 0]);
+/// ```
+pub fn sixbit_to_binary(codes: &[u8]) -> Result<Vec<u32>, String> {
+    for code in codes.iter() {
+        check_code(*code)?;
+    }
+
+    decimals_to_binary(&codes.to_vec())
+}
+
+/// This function packs six bit codes into a contiguous bitstream of bytes.
+///
+/// The codes are written most significant bit first, so four codes fill three bytes exactly.
+/// If the bits don't fill the last byte it is padded with zero bits.
+///
+/// Each code should be in the range `0 - 63`, otherwise an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::sixbit::*;
+///
+/// assert_eq!(pack_sixbit(&[63, 0, 63, 0]).unwrap(), vec![0b11111100, 0b00001111, 0b11000000]);
+/// ```
+pub fn pack_sixbit(codes: &[u8]) -> Result<Vec<u8>, String> {
+    let mut packed = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for code in codes.iter() {
+        check_code(*code)?;
+
+        buffer = (buffer << 6) | *code as u32;
+        bits += 6;
+
+        while bits >= 8 {
+            bits -= 8;
+            packed.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    if bits > 0 {
+        packed.push((buffer << (8 - bits)) as u8);
+    }
+
+    Ok(packed)
+}
+
+/// This function unpacks a bitstream of bytes into six bit codes.
+///
+/// As the padding at the end of a bitstream can be long enough to look like another code,
+/// the number of codes to read has to be passed in. If the bytes are too short to hold that
+/// many codes an error will be thrown.
+///
+/// # Example
+/// ```
+/// use ascii_converter::sixbit::*;
+///
+/// assert_eq!(unpack_sixbit(&[0b11111100, 0b00001111, 0b11000000], 3).unwrap(), vec![63, 0, 63]);
+/// ```
+pub fn unpack_sixbit(packed: &[u8], count: usize) -> Result<Vec<u8>, String> {
+    if count.checked_mul(6).filter(|bits| *bits <= packed.len() * 8).is_none() {
+        return Err(format!("{} bytes can't hold {} six bit codes", packed.len(), count));
+    }
+
+    let mut codes = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for byte in packed.iter() {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+
+        while bits >= 6 && codes.len() < count {
+            bits -= 6;
+            codes.push((buffer >> bits) as u8 & 0x3F);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Ok(codes)
+}
+
+fn check_code(code: u8) -> Result<(), String> {
+    if code > 63 {
+        return Err(format!("{} isn't a six bit code", code));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod string_to_sixbit_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_sixbit_test_round_trip() {
+            let input: String = (32..=95u8).map(|d| d as char).collect();
+
+            for charset in [SixBitCharset::DecSixbit, SixBitCharset::Ascii6].iter() {
+                let codes = string_to_sixbit(&input, *charset).unwrap();
+
+                assert_eq!(sixbit_to_string(&codes, *charset), Ok(input.clone()));
+            }
+        }
+
+        #[test]
+        fn string_to_sixbit_test_unhappy_path() {
+            assert_eq!(string_to_sixbit("a{b", SixBitCharset::DecSixbit), Err("the character '{' has no six bit code".to_string()));
+        }
+
+        #[test]
+        fn sixbit_to_string_test_unhappy_path() {
+            assert_eq!(sixbit_to_string(&[64], SixBitCharset::Ascii6), Err("64 isn't a six bit code".to_string()));
+        }
+    }
+
+    mod pack_sixbit_tests {
+        use super::super::*;
+
+        #[test]
+        fn pack_sixbit_test_pdp10_word() {
+            // "SIXBIT" fills exactly one 36-bit PDP-10 word, which is four and a half bytes.
+            let codes = string_to_sixbit("SIXBIT", SixBitCharset::DecSixbit).unwrap();
+
+            assert_eq!(pack_sixbit(&codes), Ok(vec![0xCE, 0x9E, 0x22, 0xA7, 0x40]));
+        }
+
+        #[test]
+        fn pack_sixbit_test_round_trip() {
+            let codes: Vec<u8> = (0..64).collect();
+            let packed = pack_sixbit(&codes).unwrap();
+
+            assert_eq!(packed.len(), 48);
+            assert_eq!(unpack_sixbit(&packed, 64), Ok(codes));
+        }
+
+        #[test]
+        fn pack_sixbit_test_unhappy_path() {
+            assert_eq!(pack_sixbit(&[1, 100]), Err("100 isn't a six bit code".to_string()));
+        }
+
+        #[test]
+        fn unpack_sixbit_test_unhappy_path() {
+            assert_eq!(unpack_sixbit(&[0, 0], 3), Err("2 bytes can't hold 3 six bit codes".to_string()));
+        }
+
+        #[test]
+        fn unpack_sixbit_test_huge_count() {
+            assert_eq!(unpack_sixbit(&[0, 0], usize::MAX), Err(format!("2 bytes can't hold {} six bit codes", usize::MAX)));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Generating and parsing byte array literals for C, Rust and Python source code.
+//!
+//! This is the same job `xxd -i` does for C: data is written out as an array of hexadecimal
+//! byte literals that can be pasted into source code, and array literals can be read back
+//! into bytes.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::source_array::*;
+//!
+//! let source = bytes_to_source(b"hi", "greeting", Language::Rust, 12).unwrap();
+//!
+//! assert_eq!(source, "const GREETING: [u8; 2] = [\n    0x68, 0x69,\n];\n");
+//! assert_eq!(source_to_bytes(&source, Language::Rust).unwrap(), b"hi".to_vec());
+//! ```
+
+/// The languages array literals can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// An `unsigned char` array followed by an `unsigned int` holding its length, like `xxd -i`.
+    C,
+    /// A `const` array of `u8`, with its name in upper case.
+    Rust,
+    /// A `bytes` object made from a list.
+    Python,
+}
+
+/// This function writes bytes out as an array literal in the chosen language.
+///
+/// Each line holds up to `per_line` bytes written as two digit hexadecimal literals. The name is
+/// used as written for C and Python, and upper cased for Rust as constants are named that way.
+///
+/// An error will be thrown if `per_line` is `0` or the name isn't a valid identifier.
+///
+/// # Example
+/// ```
+/// use ascii_converter::source_array::*;
+///
+/// assert_eq!(
+///     bytes_to_source(b"hello", "hello_txt", Language::C, 4).unwrap(),
+///     "unsigned char hello_txt[] = {\n  0x68, 0x65, 0x6c, 0x6c,\n  0x6f\n};\nunsigned int hello_txt_len = 5;\n"
+/// );
+/// assert_eq!(bytes_to_source(b"hello", "DATA", Language::Python, 8).unwrap(), "DATA = bytes([\n    0x68, 0x65, 0x6c, 0x6c, 0x6f,\n])\n");
+/// ```
+pub fn bytes_to_source(data: &[u8], name: &str, language: Language, per_line: usize) -> Result<String, String> {
+    if per_line == 0 {
+        return Err("there must be at least 1 byte per line".to_string());
+    }
+
+    if !is_identifier(name) {
+        return Err(format!("{:?} isn't a valid identifier", name));
+    }
+
+    let indent = if language == Language::C { "  " } else { "    " };
+
+    let mut lines: Vec<String> = data.chunks(per_line)
+        .map(|chunk| {
+            let bytes: Vec<String> = chunk.iter().map(|b| format!("0x{:02x}", b)).collect();
+
+            format!("{}{}", indent, bytes.join(", "))
+        })
+        .collect();
+
+    if language != Language::C {
+        if let Some(last) = lines.last_mut() {
+            last.push(',');
+        }
+    }
+
+    let mut body = lines.join(",\n");
+
+    if !body.is_empty() {
+        body.push('\n');
+    }
+
+    Ok(match language {
+        Language::C => format!("unsigned char {}[] = {{\n{}}};\nunsigned int {}_len = {};\n", name, body, name, data.len()),
+        Language::Rust => format!("const {}: [u8; {}] = [\n{}];\n", name.to_ascii_uppercase(), data.len(), body),
+        Language::Python => format!("{} = bytes([\n{}])\n", name, body),
+    })
+}
+
+/// This function reads the bytes back out of an array literal in the chosen language.
+///
+/// The first array literal after the `=` is read. Decimal, hexadecimal, octal and binary
+/// literals are accepted in the forms the language allows, along with comments and trailing commas.
+/// In C a number starting with `0` is octal, as it is in the language.
+///
+/// An error will be thrown if no array literal is found, a value isn't a valid literal or a
+/// value is above `255`.
+///
+/// # Example
+/// ```
+/// use ascii_converter::source_array::*;
+///
+/// let source = "static const uint8_t table[3] = { 0x41, 066, /* C */ 67 };";
+///
+/// assert_eq!(source_to_bytes(source, Language::C).unwrap(), vec![65, 54, 67]);
+/// assert_eq!(source_to_bytes("x = bytes([0x100])", Language::Python), Err("the value 0x100 is too large for a byte".to_string()));
+/// ```
+pub fn source_to_bytes(source: &str, language: Language) -> Result<Vec<u8>, String> {
+    let source = strip_comments(source, language);
+    let start = source.find('=').map(|i| i + 1).unwrap_or(0);
+    let (open, close) = if language == Language::C { ('{', '}') } else { ('[', ']') };
+
+    let body = source[start..]
+        .find(open)
+        .and_then(|i| {
+            let rest = &source[start + i + 1..];
+
+            rest.find(close).map(|j| &rest[..j])
+        })
+        .ok_or_else(|| "no array literal was found".to_string())?;
+
+    body.split(',')
+        .map(|literal| literal.trim())
+        .filter(|literal| !literal.is_empty())
+        .map(|literal| parse_literal(literal, language))
+        .collect()
+}
+
+fn is_identifier(name: &str) -> bool {
+    match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+fn strip_comments(source: &str, language: Language) -> String {
+    let mut stripped = String::new();
+    let mut rest = source;
+
+    loop {
+        let line_comment = if language == Language::Python { rest.find('#') } else { rest.find("//") };
+        let block_comment = if language == Language::Python { None } else { rest.find("/*") };
+
+        match (line_comment, block_comment) {
+            (Some(l), b) if l < b.unwrap_or(usize::MAX) => {
+                stripped.push_str(&rest[..l]);
+                rest = rest[l..].find('\n').map_or("", |end| &rest[l + end..]);
+            }
+            (_, Some(b)) => {
+                stripped.push_str(&rest[..b]);
+                stripped.push(' ');
+                rest = rest[b + 2..].find("*/").map_or("", |end| &rest[b + 2 + end + 2..]);
+            }
+            _ => {
+                stripped.push_str(rest);
+                return stripped;
+            }
+        }
+    }
+}
+
+fn parse_literal(literal: &str, language: Language) -> Result<u8, String> {
+    let invalid = || format!("{:?} isn't a valid byte literal", literal);
+
+    let digits = match language {
+        Language::C => literal.trim_end_matches(&['u', 'U'][..]),
+        Language::Rust => literal.trim_end_matches("u8").trim_end_matches('_'),
+        Language::Python => literal,
+    };
+
+    let lower = digits.to_ascii_lowercase();
+
+    let (radix, digits) = if let Some(hex) = lower.strip_prefix("0x") {
+        (16, hex)
+    } else if let Some(bin) = lower.strip_prefix("0b") {
+        (2, bin)
+    } else if let Some(oct) = lower.strip_prefix("0o").filter(|_| language != Language::C) {
+        (8, oct)
+    } else if language == Language::C && lower.len() > 1 && lower.starts_with('0') {
+        (8, &lower[1..])
+    } else {
+        (10, &lower[..])
+    };
+
+    let digits = if language == Language::C { digits.to_string() } else { digits.replace('_', "") };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(invalid());
+    }
+
+    match u8::from_str_radix(&digits, radix) {
+        Ok(byte) => Ok(byte),
+        Err(_) => Err(format!("the value {} is too large for a byte", literal)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod bytes_to_source_tests {
+        use super::super::*;
+
+        #[test]
+        fn bytes_to_source_test_round_trip() {
+            let data: Vec<u8> = (0..=255).collect();
+
+            for language in [Language::C, Language::Rust, Language::Python].iter() {
+                let source = bytes_to_source(&data, "table", *language, 12).unwrap();
+
+                assert_eq!(source.lines().count(), if *language == Language::C { 25 } else { 24 });
+                assert_eq!(source_to_bytes(&source, *language), Ok(data.clone()));
+            }
+        }
+
+        #[test]
+        fn bytes_to_source_test_rust_name_is_upper_case() {
+            assert_eq!(bytes_to_source(b"A", "font_data", Language::Rust, 12), Ok("const FONT_DATA: [u8; 1] = [\n    0x41,\n];\n".to_string()));
+        }
+
+        #[test]
+        fn bytes_to_source_test_empty() {
+            assert_eq!(bytes_to_source(&[], "EMPTY", Language::Rust, 12), Ok("const EMPTY: [u8; 0] = [\n];\n".to_string()));
+            assert_eq!(source_to_bytes("const EMPTY: [u8; 0] = [];", Language::Rust), Ok(vec![]));
+        }
+
+        #[test]
+        fn bytes_to_source_test_unhappy_path() {
+            assert_eq!(bytes_to_source(b"hi", "data", Language::C, 0), Err("there must be at least 1 byte per line".to_string()));
+            assert_eq!(bytes_to_source(b"hi", "1st-data", Language::C, 12), Err("\"1st-data\" isn't a valid identifier".to_string()));
+        }
+    }
+
+    mod source_to_bytes_tests {
+        use super::super::*;
+
+        #[test]
+        fn source_to_bytes_test_literal_forms() {
+            assert_eq!(source_to_bytes("char d[] = {0X7F, 0b101, 017, 9u, 0};", Language::C), Ok(vec![127, 5, 15, 9, 0]));
+            assert_eq!(source_to_bytes("let d = [0xFF_u8, 0o17, 1_0, 0b1111_0000]; // [1]", Language::Rust), Ok(vec![255, 15, 10, 240]));
+            assert_eq!(source_to_bytes("d = bytes([\n    0x41,  # [A]\n    0o101,\n])", Language::Python), Ok(vec![65, 65]));
+        }
+
+        #[test]
+        fn source_to_bytes_test_unhappy_path() {
+            assert_eq!(source_to_bytes("int x = 5;", Language::C), Err("no array literal was found".to_string()));
+            assert_eq!(source_to_bytes("char d[] = {0x4G};", Language::C), Err("\"0x4G\" isn't a valid byte literal".to_string()));
+            assert_eq!(source_to_bytes("char d[] = {08};", Language::C), Err("\"08\" isn't a valid byte literal".to_string()));
+            assert_eq!(source_to_bytes("const D: [u8; 1] = [256];", Language::Rust), Err("the value 256 is too large for a byte".to_string()));
+        }
+    }
+}
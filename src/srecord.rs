@@ -0,0 +1,336 @@
+//! Reading and writing Motorola S-record files (S19, S28 and S37).
+//!
+//! Each record is a line such as `S1130000285F245F2212226A000424290008237C2A`, made of the
+//! record type, a byte count, an address, the data and a checksum. The record types are:
+//!
+//! - `S0` - a header, usually holding the file name as ascii text.
+//!
+//! - `S1`, `S2`, `S3` - data with a 16, 24 or 32 bit address.
+//!
+//! - `S5`, `S6` - the number of data records, as a 16 or 24 bit value.
+//!
+//! - `S7`, `S8`, `S9` - the start address, as a 32, 24 or 16 bit value.
+//!
+//! Data is parsed into the same [`Segment`]s used by the [`intel_hex`](crate::intel_hex) module.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::srecord::*;
+//!
+//! let file = "S00600004844521B\nS107000001020304EE\nS5030001FB\nS9030000FC\n";
+//!
+//! let srec = parse_srecords(file).unwrap();
+//!
+//! assert_eq!(srec.header, b"HDR".to_vec());
+//! assert_eq!(srec.segments[0].data, vec![1, 2, 3, 4]);
+//! assert_eq!(write_srecords(&srec, AddressSize::Bits16, 16).unwrap(), file);
+//! ```
+
+use crate::intel_hex::add_data;
+pub use crate::intel_hex::Segment;
+use crate::{dec_to_hex_pairs, hex_pairs_to_dec};
+
+/// The size of the addresses used by the data records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressSize {
+    /// 16 bit addresses, `S1` data records and an `S9` start address (S19 files).
+    Bits16,
+    /// 24 bit addresses, `S2` data records and an `S8` start address (S28 files).
+    Bits24,
+    /// 32 bit addresses, `S3` data records and an `S7` start address (S37 files).
+    Bits32,
+}
+
+impl AddressSize {
+    fn bytes(self) -> usize {
+        match self {
+            AddressSize::Bits16 => 2,
+            AddressSize::Bits24 => 3,
+            AddressSize::Bits32 => 4,
+        }
+    }
+}
+
+/// The contents of an S-record file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SRecords {
+    /// The data of the `S0` header record.
+    pub header: Vec<u8>,
+    /// The memory segments, in the order they appear in the file.
+    pub segments: Vec<Segment>,
+    /// The start address from the `S7`, `S8` or `S9` record, if the file has one.
+    pub start_address: Option<u32>,
+}
+
+/// This function parses the text of an S-record file.
+///
+/// Each record's checksum is checked and data records that follow on from each other are joined
+/// into a single segment. If the file has an `S5` or `S6` record, its count must match the number
+/// of data records read before it. Blank lines are ignored.
+///
+/// Any error is returned with the line number it was found on.
+///
+/// # Example
+/// ```
+/// use ascii_converter::srecord::*;
+///
+/// let srec = parse_srecords("S3090800000001020304E4\nS70508000000F2\n").unwrap();
+///
+/// assert_eq!(srec.segments, vec![Segment { address: 0x0800_0000, data: vec![1, 2, 3, 4] }]);
+/// assert_eq!(srec.start_address, Some(0x0800_0000));
+///
+/// assert_eq!(parse_srecords("S1070000010203046F\n"), Err("line 1: checksum is 6F but should be EE".to_string()));
+/// ```
+pub fn parse_srecords(text: &str) -> Result<SRecords, String> {
+    let mut srec = SRecords::default();
+    let mut data_records: u32 = 0;
+    let mut finished = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if finished {
+            return Err(format!("line {}: record after the termination record", i + 1));
+        }
+
+        let (record_type, address, data) = parse_record(line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+
+        match record_type {
+            0 => srec.header = data,
+            1..=3 => {
+                add_data(&mut srec.segments, address, data);
+                data_records += 1;
+            }
+            5 | 6 => {
+                if address != data_records {
+                    return Err(format!("line {}: record count is {} but {} data records were read", i + 1, address, data_records));
+                }
+            }
+            7..=9 => {
+                srec.start_address = Some(address);
+                finished = true;
+            }
+            _ => return Err(format!("line {}: unknown record type S{}", i + 1, record_type)),
+        }
+    }
+
+    Ok(srec)
+}
+
+/// This function writes the contents of an S-record file out as text.
+///
+/// The file starts with an `S0` header record, followed by the data records using the address
+/// size passed in, each holding up to `record_size` bytes. A count record and a termination
+/// record are added to the end, the termination record uses address `0` if there is no start address.
+///
+/// An error will be thrown if an address doesn't fit in the address size, `record_size` is `0`
+/// or too large for the record's byte count, or there are more data records than an `S6` count
+/// record can hold.
+///
+/// # Example
+/// ```
+/// use ascii_converter::srecord::*;
+///
+/// let srec = SRecords {
+///     header: vec![],
+///     segments: vec![Segment { address: 0x0100, data: vec![0xAB, 0xCD] }],
+///     start_address: Some(0x0100),
+/// };
+///
+/// assert_eq!(write_srecords(&srec, AddressSize::Bits24, 16).unwrap(), "S0030000FC\nS206000100ABCD80\nS5030001FB\nS804000100FA\n");
+/// ```
+pub fn write_srecords(srec: &SRecords, address_size: AddressSize, record_size: u8) -> Result<String, String> {
+    let address_bytes = address_size.bytes();
+    let max_record_size = 255 - address_bytes - 1;
+
+    if record_size == 0 || record_size as usize > max_record_size {
+        return Err(format!("the record size must be between 1 and {} bytes", max_record_size));
+    }
+
+    let limit: u64 = 1 << (address_bytes * 8);
+
+    let mut text = record(0, 0, 2, &srec.header)?;
+    let mut count: u32 = 0;
+
+    for segment in srec.segments.iter() {
+        if segment.address as u64 + segment.data.len() as u64 > limit {
+            return Err(format!("the segment at {:X} doesn't fit in {} bit addresses", segment.address, address_bytes * 8));
+        }
+
+        let mut address = segment.address;
+
+        for chunk in segment.data.chunks(record_size as usize) {
+            text.push_str(&record(address_bytes as u8 - 1, address, address_bytes, chunk)?);
+
+            address = address.wrapping_add(chunk.len() as u32);
+            count += 1;
+        }
+    }
+
+    if count <= 0xFFFF {
+        text.push_str(&record(5, count, 2, &[])?);
+    } else if count > 0xFF_FFFF {
+        return Err(format!("{} data records is more than a count record can hold", count));
+    } else {
+        text.push_str(&record(6, count, 3, &[])?);
+    }
+
+    let start = srec.start_address.unwrap_or(0);
+
+    if start as u64 >= limit {
+        return Err(format!("the start address {:X} doesn't fit in {} bit addresses", start, address_bytes * 8));
+    }
+
+    text.push_str(&record(11 - address_bytes as u8, start, address_bytes, &[])?);
+
+    Ok(text)
+}
+
+// Returns the record type, the address (or count for S5 and S6) and the data.
+fn parse_record(line: &str) -> Result<(u8, u32, Vec<u8>), String> {
+    let mut chars = line.chars();
+
+    if chars.next() != Some('S') {
+        return Err("record doesn't start with 'S'".to_string());
+    }
+
+    let record_type = match chars.next() {
+        Some(c) if c.is_ascii_digit() && c != '4' => c as u8 - b'0',
+        Some(c) => return Err(format!("unknown record type S{}", c)),
+        None => return Err("record is too short".to_string()),
+    };
+
+    let address_bytes = match record_type {
+        0 | 1 | 5 | 9 => 2,
+        2 | 6 | 8 => 3,
+        _ => 4,
+    };
+
+    let bytes = hex_pairs_to_dec(&line[2..])?;
+
+    if bytes.is_empty() || bytes.len() != bytes[0] as usize + 1 {
+        return Err("record length doesn't match its byte count".to_string());
+    }
+
+    if bytes.len() < address_bytes + 2 {
+        return Err("record is too short".to_string());
+    }
+
+    let (body, checksum) = bytes.split_at(bytes.len() - 1);
+    let expected = checksum_of(body);
+
+    if checksum[0] != expected {
+        return Err(format!("checksum is {:02X} but should be {:02X}", checksum[0], expected));
+    }
+
+    let address = body[1..=address_bytes].iter().fold(0, |a, b| (a << 8) | *b as u32);
+
+    Ok((record_type, address, body[address_bytes + 1..].to_vec()))
+}
+
+fn record(record_type: u8, address: u32, address_bytes: usize, data: &[u8]) -> Result<String, String> {
+    if data.len() + address_bytes + 1 > 255 {
+        return Err("the record is too long for its byte count".to_string());
+    }
+
+    let mut bytes = vec![(address_bytes + data.len() + 1) as u8];
+
+    bytes.extend_from_slice(&address.to_be_bytes()[4 - address_bytes..]);
+    bytes.extend_from_slice(data);
+    bytes.push(checksum_of(&bytes));
+
+    Ok(format!("S{}{}\n", record_type, dec_to_hex_pairs(&bytes)))
+}
+
+// The ones' complement of the least significant byte of the sum.
+fn checksum_of(bytes: &[u8]) -> u8 {
+    !bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod parse_srecords_tests {
+        use super::super::*;
+
+        #[test]
+        fn parse_srecords_test_happy_path() {
+            let file = "S00F000068656C6C6F202020202000003C\n\
+                        S11F00007C0802A6900100049421FFF07C6C1B787C8C23783C6000003863000026\n\
+                        S11F001C4BFFFFE5398000007D83637880010014382100107C0803A64E800020E9\n\
+                        S111003848656C6C6F20776F726C642E0A0042\n\
+                        S5030003F9\n\
+                        S9030000FC\n";
+
+            let srec = parse_srecords(file).unwrap();
+
+            assert_eq!(srec.header, b"hello     \0\0".to_vec());
+            assert_eq!(srec.segments.len(), 1);
+            assert_eq!(srec.segments[0].data.len(), 70);
+            assert!(srec.segments[0].data.ends_with(b"Hello world.\n\0"));
+            assert_eq!(srec.start_address, Some(0));
+        }
+
+        #[test]
+        fn parse_srecords_test_unhappy_path() {
+            assert_eq!(parse_srecords("1030000FC\n"), Err("line 1: record doesn't start with 'S'".to_string()));
+            assert_eq!(parse_srecords("S4030000FC\n"), Err("line 1: unknown record type S4".to_string()));
+            assert_eq!(parse_srecords("S1040000FC\n"), Err("line 1: record length doesn't match its byte count".to_string()));
+            assert_eq!(parse_srecords("S107000001020304EE\nS5030002FA\n"), Err("line 2: record count is 2 but 1 data records were read".to_string()));
+            assert_eq!(parse_srecords("S9030000FC\nS9030000FC\n"), Err("line 2: record after the termination record".to_string()));
+        }
+    }
+
+    mod write_srecords_tests {
+        use super::super::*;
+
+        #[test]
+        fn write_srecords_test_round_trip() {
+            for address_size in [AddressSize::Bits16, AddressSize::Bits24, AddressSize::Bits32].iter() {
+                let srec = SRecords {
+                    header: b"test.bin".to_vec(),
+                    segments: vec![
+                        Segment { address: 0x0000, data: (0..100).collect() },
+                        Segment { address: 0x8000, data: b"boot".to_vec() },
+                    ],
+                    start_address: Some(0x8000),
+                };
+
+                let text = write_srecords(&srec, *address_size, 32).unwrap();
+
+                assert_eq!(text.lines().count(), 8);
+                assert_eq!(parse_srecords(&text), Ok(srec));
+            }
+        }
+
+        #[test]
+        fn write_srecords_test_top_of_address_space() {
+            let srec = SRecords {
+                header: vec![],
+                segments: vec![Segment { address: 0xFFFF_FFF0, data: (0..16).collect() }],
+                start_address: Some(0xFFFF_FFF0),
+            };
+
+            let text = write_srecords(&srec, AddressSize::Bits32, 8).unwrap();
+
+            assert!(text.contains("\nS30DFFFFFFF8"));
+            assert_eq!(parse_srecords(&text), Ok(srec));
+        }
+
+        #[test]
+        fn write_srecords_test_unhappy_path() {
+            let srec = SRecords {
+                header: vec![],
+                segments: vec![Segment { address: 0xFFFF, data: vec![1, 2] }],
+                start_address: None,
+            };
+
+            assert_eq!(write_srecords(&srec, AddressSize::Bits16, 16), Err("the segment at FFFF doesn't fit in 16 bit addresses".to_string()));
+            assert_eq!(write_srecords(&srec, AddressSize::Bits32, 251), Err("the record size must be between 1 and 250 bytes".to_string()));
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Transliteration of Unicode text into ascii.
+//!
+//! The crate's string conversions refuse any character outside the ascii table. This module
+//! replaces those characters with the closest ascii spelling instead, using built-in tables for
+//! Latin letters with diacritics, Greek, Cyrillic and common typographic punctuation such as
+//! smart quotes, dashes and ellipses. Combining accents are dropped, and characters with no
+//! ascii spelling become `?`.
+//!
+//! The `_with` string conversions in this module work like the crate's own, but take a
+//! [`NonAscii`] flag to choose between the usual rejection and transliteration.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::transliterate::*;
+//!
+//! assert_eq!(transliterate("Crème brûlée"), "Creme brulee");
+//! assert_eq!(transliterate("Straße"), "Strasse");
+//! assert_eq!(transliterate("“Αθήνα” — Москва…"), "\"Athina\" - Moskva...");
+//! ```
+
+use crate::{string_to_binary, string_to_decimals, string_to_hexadecimal};
+
+/// U+00A0 to U+00FF.
+const LATIN_1: [&str; 96] = [
+    " ", "!", "c", "GBP", "?", "JPY", "|", "S", "\"", "(c)", "a", "<<", "!", "", "(r)", "-",
+    "deg", "+-", "2", "3", "'", "u", "P", "*", ",", "1", "o", ">>", "1/4", "1/2", "3/4", "?",
+    "A", "A", "A", "A", "A", "A", "AE", "C", "E", "E", "E", "E", "I", "I", "I", "I",
+    "D", "N", "O", "O", "O", "O", "O", "x", "O", "U", "U", "U", "U", "Y", "Th", "ss",
+    "a", "a", "a", "a", "a", "a", "ae", "c", "e", "e", "e", "e", "i", "i", "i", "i",
+    "d", "n", "o", "o", "o", "o", "o", "/", "o", "u", "u", "u", "u", "y", "th", "y",
+];
+
+/// U+0100 to U+017F.
+const LATIN_EXTENDED_A: [&str; 128] = [
+    "A", "a", "A", "a", "A", "a", "C", "c", "C", "c", "C", "c", "C", "c", "D", "d",
+    "D", "d", "E", "e", "E", "e", "E", "e", "E", "e", "E", "e", "G", "g", "G", "g",
+    "G", "g", "G", "g", "H", "h", "H", "h", "I", "i", "I", "i", "I", "i", "I", "i",
+    "I", "i", "IJ", "ij", "J", "j", "K", "k", "k", "L", "l", "L", "l", "L", "l", "L",
+    "l", "L", "l", "N", "n", "N", "n", "N", "n", "'n", "NG", "ng", "O", "o", "O", "o",
+    "O", "o", "OE", "oe", "R", "r", "R", "r", "R", "r", "S", "s", "S", "s", "S", "s",
+    "S", "s", "T", "t", "T", "t", "T", "t", "U", "u", "U", "u", "U", "u", "U", "u",
+    "U", "u", "U", "u", "W", "w", "Y", "y", "Y", "Z", "z", "Z", "z", "Z", "z", "s",
+];
+
+/// U+0386 to U+03CE, following ELOT 743.
+const GREEK: [&str; 73] = [
+    "A", ";", "E", "I", "I", "?", "O", "?", "Y", "O", "i",
+    "A", "V", "G", "D", "E", "Z", "I", "Th", "I", "K", "L", "M", "N", "X", "O",
+    "P", "R", "?", "S", "T", "Y", "F", "Ch", "Ps", "O", "I", "Y", "a", "e", "i", "i",
+    "y", "a", "v", "g", "d", "e", "z", "i", "th", "i", "k", "l", "m", "n", "x", "o",
+    "p", "r", "s", "s", "t", "y", "f", "ch", "ps", "o", "i", "y", "o", "y", "o",
+];
+
+/// U+0400 to U+045F.
+const CYRILLIC: [&str; 96] = [
+    "E", "Yo", "Dj", "Gj", "Ye", "Dz", "I", "Yi", "J", "Lj", "Nj", "C", "Kj", "I", "U", "Dz",
+    "A", "B", "V", "G", "D", "E", "Zh", "Z", "I", "Y", "K", "L", "M", "N", "O", "P",
+    "R", "S", "T", "U", "F", "Kh", "Ts", "Ch", "Sh", "Shch", "", "Y", "", "E", "Yu", "Ya",
+    "a", "b", "v", "g", "d", "e", "zh", "z", "i", "y", "k", "l", "m", "n", "o", "p",
+    "r", "s", "t", "u", "f", "kh", "ts", "ch", "sh", "shch", "", "y", "", "e", "yu", "ya",
+    "e", "yo", "dj", "gj", "ye", "dz", "i", "yi", "j", "lj", "nj", "c", "kj", "i", "u", "dz",
+];
+
+const OTHER: [(char, &str); 42] = [
+    ('ƒ', "f"), ('Ș', "S"), ('ș', "s"), ('Ț', "T"), ('ț', "t"), ('ẞ', "SS"),
+    ('\u{2002}', " "), ('\u{2003}', " "), ('\u{2009}', " "), ('\u{200A}', " "), ('\u{200B}', ""),
+    ('‐', "-"), ('‑', "-"), ('‒', "-"), ('–', "-"), ('—', "-"), ('―', "-"), ('−', "-"),
+    ('‘', "'"), ('’', "'"), ('‚', ","), ('‛', "'"), ('“', "\""), ('”', "\""), ('„', "\""), ('‟', "\""),
+    ('′', "'"), ('″', "\""), ('‹', "<"), ('›', ">"), ('•', "*"), ('…', "..."), ('⁄', "/"),
+    ('€', "EUR"), ('™', "TM"), ('←', "<-"), ('→', "->"), ('≤', "<="), ('≥', ">="), ('≠', "!="),
+    ('\u{3000}', " "), ('\u{FEFF}', ""),
+];
+
+/// How the string conversions in this module handle characters outside the ascii table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonAscii {
+    /// Throw an error, as the crate's own string conversions do.
+    Reject,
+    /// Replace the character with its ascii transliteration.
+    Transliterate,
+}
+
+/// This function replaces every character outside the ascii table with its closest ascii spelling.
+///
+/// Ascii characters are left as they are. Combining accents are removed and characters the
+/// tables don't cover are replaced with `?`.
+///
+/// # Example
+/// ```
+/// use ascii_converter::transliterate::*;
+///
+/// assert_eq!(transliterate("Łódź, Ελλάδα, Україна"), "Lodz, Ellada, Ukrayina");
+/// assert_eq!(transliterate("e\u{0301}t\u{00E9} ☢"), "ete ?");
+/// ```
+pub fn transliterate(txt: &str) -> String {
+    let mut ascii = String::new();
+
+    for c in txt.chars() {
+        if c.is_ascii() {
+            ascii.push(c);
+        } else {
+            ascii.push_str(transliterate_char(c).unwrap_or("?"));
+        }
+    }
+
+    ascii
+}
+
+/// This function returns a string's decimal values, handling non-ascii characters as chosen.
+///
+/// With [`NonAscii::Reject`] this is the same as [`crate::string_to_decimals`].
+///
+/// # Example
+/// ```
+/// use ascii_converter::transliterate::*;
+///
+/// assert_eq!(string_to_decimals_with("ñ", NonAscii::Transliterate).unwrap(), vec![110]);
+/// assert_eq!(string_to_decimals_with("ñ", NonAscii::Reject), Err("A character in the string isn't apart of the ascii table".to_string()));
+/// ```
+pub fn string_to_decimals_with(txt: &str, non_ascii: NonAscii) -> Result<Vec<u8>, String> {
+    match non_ascii {
+        NonAscii::Reject => string_to_decimals(txt),
+        NonAscii::Transliterate => string_to_decimals(&transliterate(txt)),
+    }
+}
+
+/// This function returns a string's hexadecimal numbers, handling non-ascii characters as chosen.
+///
+/// With [`NonAscii::Reject`] this is the same as [`crate::string_to_hexadecimal`].
+///
+/// # Example
+/// ```
+/// use ascii_converter::transliterate::*;
+///
+/// assert_eq!(string_to_hexadecimal_with("é", NonAscii::Transliterate).unwrap(), vec!["65".to_string()]);
+/// ```
+pub fn string_to_hexadecimal_with(txt: &str, non_ascii: NonAscii) -> Result<Vec<String>, String> {
+    match non_ascii {
+        NonAscii::Reject => string_to_hexadecimal(&txt.to_string()),
+        NonAscii::Transliterate => string_to_hexadecimal(&transliterate(txt)),
+    }
+}
+
+/// This function returns the binary numbers of a string, handling non-ascii characters as chosen.
+///
+/// With [`NonAscii::Reject`] this is the same as [`crate::string_to_binary`].
+///
+/// # Example
+/// ```
+/// use ascii_converter::transliterate::*;
+///
+/// assert_eq!(string_to_binary_with("ß", NonAscii::Transliterate).unwrap(), vec![1110011, 1110011]);
+/// ```
+pub fn string_to_binary_with(txt: &str, non_ascii: NonAscii) -> Result<Vec<u32>, String> {
+    match non_ascii {
+        NonAscii::Reject => string_to_binary(txt),
+        NonAscii::Transliterate => string_to_binary(&transliterate(txt)),
+    }
+}
+
+fn transliterate_char(c: char) -> Option<&'static str> {
+    let code = c as usize;
+
+    match code {
+        0xA0..=0xFF => Some(LATIN_1[code - 0xA0]),
+        0x100..=0x17F => Some(LATIN_EXTENDED_A[code - 0x100]),
+        0x300..=0x36F => Some(""),
+        0x386..=0x3CE => Some(GREEK[code - 0x386]),
+        0x400..=0x45F => Some(CYRILLIC[code - 0x400]),
+        _ => OTHER.iter().find(|(other, _)| *other == c).map(|(_, ascii)| *ascii),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod transliterate_tests {
+        use super::super::*;
+
+        #[test]
+        fn transliterate_test_happy_path() {
+            assert_eq!(transliterate("Ærøskøbing Ñandú Œuvre"), "AEroskobing Nandu OEuvre");
+            assert_eq!(transliterate("Ψυχή ΘΕΑΤΡΟ"), "Psychi ThEATRO");
+            assert_eq!(transliterate("Щука, Объём, Ёж"), "Shchuka, Obyom, Yozh");
+            assert_eq!(transliterate("‘a’ – b ‚c‛ €5 ™"), "'a' - b ,c' EUR5 TM");
+        }
+
+        #[test]
+        fn transliterate_test_every_table_entry_is_ascii() {
+            for code in 0xA0..=0x45F {
+                if let Some(c) = std::char::from_u32(code) {
+                    assert!(transliterate(&c.to_string()).is_ascii());
+                }
+            }
+        }
+
+        #[test]
+        fn transliterate_test_unknown_characters() {
+            assert_eq!(transliterate("日本"), "??");
+            assert_eq!(transliterate(""), "");
+        }
+    }
+
+    mod string_to_decimals_with_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_decimals_with_test_happy_path() {
+            assert_eq!(string_to_decimals_with("Ça va", NonAscii::Transliterate), Ok(vec![67, 97, 32, 118, 97]));
+            assert_eq!(string_to_decimals_with("Ca va", NonAscii::Reject), Ok(vec![67, 97, 32, 118, 97]));
+        }
+
+        #[test]
+        fn string_to_decimals_with_test_unhappy_path() {
+            assert_eq!(string_to_decimals_with("Ça va", NonAscii::Reject), Err("A character in the string isn't apart of the ascii table".to_string()));
+        }
+    }
+
+    mod string_to_hexadecimal_with_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_hexadecimal_with_test_happy_path() {
+            let expected: Vec<String> = vec!["4F".to_string(), "45".to_string(), "2D".to_string(), "61".to_string()];
+
+            assert_eq!(string_to_hexadecimal_with("Œ–a", NonAscii::Transliterate), Ok(expected.clone()));
+            assert_eq!(string_to_hexadecimal_with("OE-a", NonAscii::Reject), Ok(expected));
+        }
+
+        #[test]
+        fn string_to_hexadecimal_with_test_unhappy_path() {
+            assert_eq!(string_to_hexadecimal_with("Œ–a", NonAscii::Reject), Err("A character in the string isn't apart of the ascii table".to_string()));
+        }
+    }
+
+    mod string_to_binary_with_tests {
+        use super::super::*;
+
+        #[test]
+        fn string_to_binary_with_test_happy_path() {
+            assert_eq!(string_to_binary_with("ø", NonAscii::Transliterate), Ok(vec![1101111]));
+        }
+
+        #[test]
+        fn string_to_binary_with_test_unhappy_path() {
+            assert_eq!(string_to_binary_with("Ça va", NonAscii::Reject), Err("A character in the string isn't apart of the ascii table".to_string()));
+        }
+    }
+}
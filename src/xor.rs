@@ -0,0 +1,218 @@
+//! Repeating-key XOR over byte vectors.
+//!
+//! Keys can be given in any of the representations supported by the crate using [`Key`], and
+//! [`single_byte_xor_brute_force`] ranks every single byte key by how much the result looks
+//! like English text.
+//!
+//! # Example
+//! ```
+//! use ascii_converter::xor::*;
+//!
+//! let data = b"attack at dawn".to_vec();
+//! let key = Key::Text("ICE");
+//!
+//! let encrypted = repeating_key_xor(&data, &key).unwrap();
+//!
+//! assert_eq!(repeating_key_xor(&encrypted, &key).unwrap(), data);
+//! ```
+
+use crate::{binary_to_decimal, hexadecimal_to_decimal, string_to_decimals};
+
+// Relative frequency of the letters a - z in English text.
+const LETTER_FREQUENCIES: [f64; 26] = [
+    0.0817, 0.0149, 0.0278, 0.0425, 0.1270, 0.0223, 0.0202, 0.0609, 0.0697, 0.0015, 0.0077,
+    0.0403, 0.0241, 0.0675, 0.0751, 0.0193, 0.0010, 0.0599, 0.0633, 0.0906, 0.0276, 0.0098,
+    0.0236, 0.0015, 0.0197, 0.0007,
+];
+
+// Spaces appear slightly more often than the letter `e`.
+const SPACE_FREQUENCY: f64 = 0.1300;
+
+/// A XOR key written in one of the crate's representations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Key<'a> {
+    /// Decimal byte values, e.g. `[75, 69, 89]`.
+    Decimals(&'a [u8]),
+    /// Hexadecimal byte values, e.g. `["4B", "45", "59"]`.
+    Hexadecimals(&'a [String]),
+    /// Binary byte values, e.g. `[1001011, 1000101, 1011001]`.
+    Binary(&'a [u32]),
+    /// Ascii text, e.g. `"KEY"`.
+    Text(&'a str),
+}
+
+impl<'a> Key<'a> {
+    /// This function returns the key's bytes as decimals.
+    ///
+    /// An error is returned if the key isn't valid for its representation.
+    ///
+    /// # Example
+    /// ```
+    /// use ascii_converter::xor::*;
+    ///
+    /// let hex = vec!["4B".to_string(), "45".to_string(), "59".to_string()];
+    ///
+    /// assert_eq!(Key::Hexadecimals(&hex).to_decimals().unwrap(), Key::Text("KEY").to_decimals().unwrap());
+    /// ```
+    pub fn to_decimals(&self) -> Result<Vec<u8>, String> {
+        match self {
+            Key::Decimals(dec) => Ok(dec.to_vec()),
+            Key::Hexadecimals(hex) => hexadecimal_to_decimal(&hex.to_vec()),
+            Key::Binary(bin) => binary_to_decimal(&bin.to_vec()),
+            Key::Text(txt) => string_to_decimals(txt),
+        }
+    }
+}
+
+/// A possible plaintext found by [`single_byte_xor_brute_force`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct XorCandidate {
+    /// The single byte key that was tried.
+    pub key: u8,
+    /// How English-like the plaintext is, higher is better.
+    pub score: f64,
+    /// The data XORed with the key.
+    pub plaintext: Vec<u8>,
+}
+
+/// This function XORs the data with the key, repeating the key as often as needed.
+///
+/// As XOR is its own inverse the same function both encrypts and decrypts.
+///
+/// An error is returned if the key is empty or isn't valid for its representation.
+///
+/// # Example
+/// ```
+/// use ascii_converter::xor::*;
+///
+/// let key = vec![1111111];
+///
+/// assert_eq!(repeating_key_xor(&[72, 105], &Key::Binary(&key)).unwrap(), vec![55, 22]);
+/// ```
+pub fn repeating_key_xor(data: &[u8], key: &Key) -> Result<Vec<u8>, String> {
+    let key = key.to_decimals()?;
+
+    if key.is_empty() {
+        return Err("the key can't be empty".to_string());
+    }
+
+    Ok(data.iter()
+        .zip(key.iter().cycle())
+        .map(|(d, k)| d ^ k)
+        .collect())
+}
+
+/// This function tries all 256 single byte keys against the data and ranks the results.
+///
+/// Each plaintext is scored by how closely its letters follow English letter frequencies and by
+/// the proportion of its bytes that are printable ascii. The candidates are returned best first.
+///
+/// # Example
+/// ```
+/// use ascii_converter::xor::*;
+///
+/// let encrypted = repeating_key_xor(b"Cooking MC's like a pound of bacon", &Key::Decimals(&[88])).unwrap();
+///
+/// let best = &single_byte_xor_brute_force(&encrypted)[0];
+///
+/// assert_eq!(best.key, 88);
+/// assert_eq!(best.plaintext, b"Cooking MC's like a pound of bacon".to_vec());
+/// ```
+pub fn single_byte_xor_brute_force(data: &[u8]) -> Vec<XorCandidate> {
+    let mut candidates: Vec<XorCandidate> = (0..=255u8)
+        .map(|key| {
+            let plaintext: Vec<u8> = data.iter().map(|d| d ^ key).collect();
+
+            XorCandidate { key, score: english_score(&plaintext), plaintext }
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap().then(a.key.cmp(&b.key)));
+
+    candidates
+}
+
+fn english_score(text: &[u8]) -> f64 {
+    if text.is_empty() {
+        return 0.0;
+    }
+
+    let mut frequency = 0.0;
+    let mut printable = 0;
+
+    for b in text.iter() {
+        if (32..=126).contains(b) || *b == b'\n' || *b == b'\r' || *b == b'\t' {
+            printable += 1;
+        }
+
+        if b.is_ascii_alphabetic() {
+            frequency += LETTER_FREQUENCIES[(b.to_ascii_lowercase() - b'a') as usize];
+        } else if *b == b' ' {
+            frequency += SPACE_FREQUENCY;
+        }
+    }
+
+    let len = text.len() as f64;
+
+    (frequency / len) * (printable as f64 / len)
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod repeating_key_xor_tests {
+        use super::super::*;
+
+        #[test]
+        fn repeating_key_xor_test_happy_path() {
+            let input = b"Burning 'em, if you ain't quick and nimble";
+            let expected = "0B3637272A2B2E63622C2E69692A23693A2A3C6324202D623D63343C2A26226324272765272A282B2F20";
+
+            let output = repeating_key_xor(input, &Key::Text("ICE")).unwrap();
+            let hex: String = output.iter().map(|b| format!("{:02X}", b)).collect();
+
+            assert_eq!(hex, expected);
+        }
+
+        #[test]
+        fn repeating_key_xor_test_key_representations() {
+            let hex = vec!["FF".to_string(), "0".to_string()];
+            let bin = vec![11111111, 0];
+
+            let expected = Ok(vec![0, 255, 15]);
+
+            assert_eq!(repeating_key_xor(&[255, 255, 240], &Key::Hexadecimals(&hex)), expected);
+            assert_eq!(repeating_key_xor(&[255, 255, 240], &Key::Binary(&bin)), expected);
+            assert_eq!(repeating_key_xor(&[255, 255, 240], &Key::Decimals(&[255, 0])), expected);
+        }
+
+        #[test]
+        fn repeating_key_xor_test_unhappy_path_empty_key() {
+            assert_eq!(repeating_key_xor(&[1, 2], &Key::Text("")), Err("the key can't be empty".to_string()));
+        }
+
+        #[test]
+        fn repeating_key_xor_test_unhappy_path_invalid_key() {
+            let hex = vec!["GG".to_string()];
+
+            assert_eq!(repeating_key_xor(&[1, 2], &Key::Hexadecimals(&hex)), Err("invalid digit found in string".to_string()));
+        }
+    }
+
+    mod single_byte_xor_brute_force_tests {
+        use super::super::*;
+
+        #[test]
+        fn single_byte_xor_brute_force_test_happy_path() {
+            let plaintext = b"Now that the party is jumping";
+            let encrypted = repeating_key_xor(plaintext, &Key::Decimals(&[0x35])).unwrap();
+
+            let candidates = single_byte_xor_brute_force(&encrypted);
+
+            assert_eq!(candidates.len(), 256);
+            assert_eq!(candidates[0].key, 0x35);
+            assert_eq!(candidates[0].plaintext, plaintext.to_vec());
+            assert!(candidates.windows(2).all(|w| w[0].score >= w[1].score));
+        }
+    }
+}